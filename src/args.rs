@@ -1,32 +1,495 @@
 use std::env;
-use std::path::PathBuf;
+use std::fmt::{self, Display, Formatter};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::str::FromStr;
 
-pub struct Args {
-    pub quiet: bool,
-    pub path: PathBuf,
+use crate::duration::Units;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Parts {
     pub run_part_1: bool,
     pub run_part_2: bool,
 }
 
+/// Where to read the puzzle input from.
+#[derive(Debug, PartialEq)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Display for Input {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Input::Stdin => write!(fmt, "stdin"),
+            Input::File(path) => write!(fmt, "{}", path.display()),
+        }
+    }
+}
+
+/// How the results are printed, unless `--quiet` is given.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Format {
+    #[default]
+    Human,
+    /// A JSON object per line, for each part that was run.
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Args {
+    pub quiet: bool,
+    /// The input given on the command line, if any.
+    pub input: Option<Input>,
+    pub parts: Parts,
+    pub units: Units,
+    pub format: Format,
+    /// Whether to compare the answers with the expected answers in `data/output/<day>`.
+    pub check: bool,
+    /// The number of runs to benchmark the solver with, if any.
+    pub bench: Option<usize>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SeasonArgs {
+    pub quiet: bool,
+    pub days: RangeInclusive<u8>,
+    pub parts: Parts,
+    pub units: Units,
+    pub format: Format,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    UnknownFlag(String),
+    MissingValue(&'static str),
+    InvalidValue(&'static str, String),
+    Conflict(&'static str, &'static str),
+    UnexpectedArgument(String),
+    InvalidDays(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Error::UnknownFlag(flag) => write!(fmt, "unknown flag {flag:?}"),
+            Error::MissingValue(flag) => write!(fmt, "missing the value of {flag}"),
+            Error::InvalidValue(flag, value) => write!(fmt, "invalid value {value:?} for {flag}"),
+            Error::Conflict(first, second) => {
+                write!(fmt, "{first} and {second} cannot be used together")
+            }
+            Error::UnexpectedArgument(arg) => write!(fmt, "unexpected argument {arg:?}"),
+            Error::InvalidDays(days) => write!(fmt, "invalid day(s) {days:?}"),
+        }
+    }
+}
+
+/// The result of parsing the command line: either the arguments to run with, or a request for
+/// the usage text.
+#[derive(Debug, PartialEq)]
+enum Parsed<T> {
+    Run(T),
+    Help,
+}
+
+/// The options shared by all binaries, as shown in the usage text.
+const OPTIONS: [(&str, &str); 6] = [
+    (
+        "-q, --quiet",
+        "Only print the answers, each followed by a NUL character, and the timings to stderr",
+    ),
+    ("-1, --one", "Only solve part 1"),
+    ("-2, --two", "Only solve part 2"),
+    (
+        "    --nanos",
+        "Print durations as plain numbers of nanoseconds",
+    ),
+    (
+        "    --format <FORMAT>",
+        "Print the results as human-readable text or JSON [possible values: human, json]",
+    ),
+    ("-h, --help", "Print this help"),
+];
+
+/// Parses the arguments of a single-day binary, or exits with the usage text.
 pub fn parse() -> Args {
+    let usage = usage(
+        "[INPUT]",
+        "The path of the puzzle input, or - for stdin [default: data/input/<day>]",
+        &[
+            (
+                "    --check",
+                "Compare the answers with the expected answers in data/output/<day>",
+            ),
+            (
+                "    --bench <RUNS>",
+                "Time parsing and solving over RUNS runs, after a few warm-up runs",
+            ),
+        ],
+    );
+
+    exit_on_error(parse_from(env::args().skip(1)), &usage)
+}
+
+/// Parses the arguments of the season binary, or exits with the usage text.
+pub fn parse_season() -> SeasonArgs {
+    let usage = usage(
+        "[DAYS]",
+        "A day (7) or a range of days (3..=10 or 3..11) [default: all days]",
+        &[],
+    );
+
+    exit_on_error(parse_season_from(env::args().skip(1)), &usage)
+}
+
+fn usage(argument: &str, description: &str, options: &[(&str, &str)]) -> String {
+    let mut usage = format!(
+        "Usage: {} [OPTIONS] [--] {argument}\n\n\
+         Arguments:\n  {argument}  {description}\n\n\
+         Options:\n",
+        program(),
+    );
+
+    let options: Vec<_> = options.iter().chain(&OPTIONS).collect();
+    let width = options.iter().map(|(flags, _)| flags.len()).max().unwrap();
+
+    for (flags, description) in options {
+        usage.push_str(&format!("  {flags:<width$}  {description}\n"));
+    }
+
+    usage.trim_end().to_string()
+}
+
+fn program() -> String {
+    env::args()
+        .next()
+        .as_deref()
+        .and_then(|program| Path::new(program).file_name())
+        .map_or("aoc".to_string(), |name| {
+            name.to_string_lossy().into_owned()
+        })
+}
+
+fn exit_on_error<T>(parsed: Result<Parsed<T>, Error>, usage: &str) -> T {
+    match parsed {
+        Ok(Parsed::Run(args)) => args,
+        Ok(Parsed::Help) => {
+            println!("{usage}");
+            process::exit(0);
+        }
+        Err(error) => {
+            eprintln!("error: {error}\n\n{usage}");
+            process::exit(2);
+        }
+    }
+}
+
+fn parse_from(args: impl IntoIterator<Item = String>) -> Result<Parsed<Args>, Error> {
+    let mut check = false;
+    let mut bench = None;
+
+    let parsed = parse_common(args, |flag, args| match flag {
+        "--check" => {
+            check = true;
+            Ok(true)
+        }
+        "--bench" => {
+            let runs = args.next().ok_or(Error::MissingValue("--bench"))?;
+            match usize::from_str(&runs) {
+                Ok(runs) if runs > 0 => bench = Some(runs),
+                _ => return Err(Error::InvalidValue("--bench", runs)),
+            }
+            Ok(true)
+        }
+        _ => Ok(false),
+    })?;
+    let Parsed::Run((common, positional)) = parsed else {
+        return Ok(Parsed::Help);
+    };
+
+    let mut positional = positional.into_iter();
+    let input = positional.next().map(|path| match path.as_str() {
+        "-" => Input::Stdin,
+        _ => Input::File(PathBuf::from(path)),
+    });
+
+    if let Some(arg) = positional.next() {
+        return Err(Error::UnexpectedArgument(arg));
+    }
+
+    if check && common.quiet {
+        return Err(Error::Conflict("--check", "--quiet"));
+    }
+
+    if bench.is_some() {
+        if common.quiet {
+            return Err(Error::Conflict("--bench", "--quiet"));
+        } else if check {
+            return Err(Error::Conflict("--bench", "--check"));
+        } else if common.format == Format::Json {
+            return Err(Error::Conflict("--bench", "--format json"));
+        }
+    }
+
+    Ok(Parsed::Run(Args {
+        quiet: common.quiet,
+        input,
+        parts: common.parts,
+        units: common.units,
+        format: common.format,
+        check,
+        bench,
+    }))
+}
+
+fn parse_season_from(args: impl IntoIterator<Item = String>) -> Result<Parsed<SeasonArgs>, Error> {
+    let Parsed::Run((common, positional)) = parse_common(args, |_, _| Ok(false))? else {
+        return Ok(Parsed::Help);
+    };
+
+    let mut positional = positional.into_iter();
+    let days = match positional.next() {
+        Some(days) => parse_days(&days).ok_or(Error::InvalidDays(days))?,
+        None => 1..=25,
+    };
+
+    if let Some(arg) = positional.next() {
+        return Err(Error::UnexpectedArgument(arg));
+    }
+
+    Ok(Parsed::Run(SeasonArgs {
+        quiet: common.quiet,
+        days,
+        parts: common.parts,
+        units: common.units,
+        format: common.format,
+    }))
+}
+
+/// The flags that are shared by all binaries.
+struct Common {
+    quiet: bool,
+    parts: Parts,
+    units: Units,
+    format: Format,
+}
+
+/// Parses the flags in `args` (without the program name), and collects the positional
+/// arguments. Everything after `--` is positional, as is a lone `-`.
+///
+/// Flags that are specific to a binary are passed to `flag`, together with the remaining
+/// arguments to take its value from. It returns whether it recognized the flag.
+fn parse_common(
+    args: impl IntoIterator<Item = String>,
+    mut flag: impl FnMut(&str, &mut dyn Iterator<Item = String>) -> Result<bool, Error>,
+) -> Result<Parsed<(Common, Vec<String>)>, Error> {
     let mut quiet = false;
-    let mut path = None;
-    let mut run_part_1 = true;
-    let mut run_part_2 = true;
+    let mut only_part_1 = false;
+    let mut only_part_2 = false;
+    let mut units = Units::Adaptive;
+    let mut format = Format::Human;
+    let mut positional = Vec::new();
+
+    let mut args = args.into_iter();
 
-    for arg in env::args() {
+    while let Some(arg) = args.next() {
         match arg.as_str() {
+            "-h" | "--help" => return Ok(Parsed::Help),
             "-q" | "--quiet" => quiet = true,
-            "-1" | "--one" => run_part_2 = false,
-            "-2" | "--two" => run_part_1 = false,
-            p => path = Some(PathBuf::from(p)),
+            "-1" | "--one" => only_part_1 = true,
+            "-2" | "--two" => only_part_2 = true,
+            "--nanos" => units = Units::Nanos,
+            "--format" => {
+                format = match args.next().ok_or(Error::MissingValue("--format"))?.as_str() {
+                    "human" => Format::Human,
+                    "json" => Format::Json,
+                    other => return Err(Error::InvalidValue("--format", other.to_string())),
+                }
+            }
+            "--" => positional.extend(args.by_ref()),
+            other if other.starts_with('-') && other != "-" => {
+                if !flag(other, &mut args)? {
+                    return Err(Error::UnknownFlag(arg));
+                }
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    if only_part_1 && only_part_2 {
+        return Err(Error::Conflict("--one", "--two"));
+    }
+
+    if quiet && format == Format::Json {
+        return Err(Error::Conflict("--quiet", "--format json"));
+    }
+
+    let parts = Parts {
+        run_part_1: !only_part_2,
+        run_part_2: !only_part_1,
+    };
+
+    Ok(Parsed::Run((
+        Common {
+            quiet,
+            parts,
+            units,
+            format,
+        },
+        positional,
+    )))
+}
+
+/// Parses a single day (`7`), an inclusive range (`3..=10`) or an exclusive range (`3..11`).
+/// Ranges that select no days, such as `10..=3` or `3..3`, and days outside `1..=25` are rejected.
+fn parse_days(days: &str) -> Option<RangeInclusive<u8>> {
+    let days = if let Some((start, end)) = days.split_once("..=") {
+        u8::from_str(start).ok()?..=u8::from_str(end).ok()?
+    } else if let Some((start, end)) = days.split_once("..") {
+        u8::from_str(start).ok()?..=u8::from_str(end).ok()?.checked_sub(1)?
+    } else {
+        let day = u8::from_str(days).ok()?;
+        day..=day
+    };
+
+    (!days.is_empty() && *days.start() >= 1 && *days.end() <= 25).then_some(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn single() {
+        assert_eq!(
+            parse_from(args(&["-q", "--one", "input"])),
+            Ok(Parsed::Run(Args {
+                quiet: true,
+                input: Some(Input::File(PathBuf::from("input"))),
+                parts: Parts {
+                    run_part_1: true,
+                    run_part_2: false,
+                },
+                units: Units::Adaptive,
+                format: Format::Human,
+                check: false,
+                bench: None,
+            }))
+        );
+        assert_eq!(parse_from(args(&["input", "--help"])), Ok(Parsed::Help));
+    }
+
+    #[test]
+    fn single_errors() {
+        assert_eq!(
+            parse_from(args(&["--qiuet", "input"])),
+            Err(Error::UnknownFlag("--qiuet".to_string()))
+        );
+        assert_eq!(
+            parse_from(args(&["-1", "-2", "input"])),
+            Err(Error::Conflict("--one", "--two"))
+        );
+        assert_eq!(
+            parse_from(args(&["--check", "-q"])),
+            Err(Error::Conflict("--check", "--quiet"))
+        );
+        assert_eq!(
+            parse_from(args(&["--bench"])),
+            Err(Error::MissingValue("--bench"))
+        );
+        assert_eq!(
+            parse_from(args(&["--format", "yaml"])),
+            Err(Error::InvalidValue("--format", "yaml".to_string()))
+        );
+        assert_eq!(
+            parse_from(args(&["--bench", "0"])),
+            Err(Error::InvalidValue("--bench", "0".to_string()))
+        );
+        assert_eq!(
+            parse_from(args(&["input", "other"])),
+            Err(Error::UnexpectedArgument("other".to_string()))
+        );
+    }
+
+    #[test]
+    fn input() {
+        let input = |arguments: &[&str]| match parse_from(args(arguments)) {
+            Ok(Parsed::Run(parsed)) => parsed.input,
+            _ => panic!("expected arguments"),
+        };
+
+        assert_eq!(input(&["-q"]), None);
+        assert_eq!(input(&["-"]), Some(Input::Stdin));
+        assert_eq!(input(&["--", "-"]), Some(Input::Stdin));
+    }
+
+    #[test]
+    fn bench() {
+        let Ok(Parsed::Run(parsed)) = parse_from(args(&["--bench", "20", "input"])) else {
+            panic!("expected arguments");
+        };
+
+        assert_eq!(parsed.bench, Some(20));
+        assert_eq!(parsed.input, Some(Input::File(PathBuf::from("input"))));
+    }
+
+    #[test]
+    fn double_dash() {
+        let Ok(Parsed::Run(parsed)) = parse_from(args(&["--", "-q"])) else {
+            panic!("expected arguments");
+        };
+
+        assert!(!parsed.quiet);
+        assert_eq!(parsed.input, Some(Input::File(PathBuf::from("-q"))));
+    }
+
+    #[test]
+    fn season() {
+        let Ok(Parsed::Run(parsed)) = parse_season_from(args(&["-2", "3..11"])) else {
+            panic!("expected arguments");
+        };
+
+        assert_eq!(parsed.days, 3..=10);
+        assert!(!parsed.parts.run_part_1);
+        assert_eq!(
+            parse_season_from(args(&["3..x"])),
+            Err(Error::InvalidDays("3..x".to_string()))
+        );
+    }
+
+    #[test]
+    fn empty_days() {
+        for days in ["10..=3", "3..3", "4..2"] {
+            assert_eq!(
+                parse_season_from(args(&[days])),
+                Err(Error::InvalidDays(days.to_string()))
+            );
         }
+
+        let Ok(Parsed::Run(parsed)) = parse_season_from(args(&["3..4"])) else {
+            panic!("expected arguments");
+        };
+        assert_eq!(parsed.days, 3..=3);
     }
 
-    Args {
-        quiet,
-        path: path.unwrap(),
-        run_part_1,
-        run_part_2,
+    #[test]
+    fn out_of_season_days() {
+        for days in ["0", "26", "0..=3", "26..=30", "20..27"] {
+            assert_eq!(
+                parse_season_from(args(&[days])),
+                Err(Error::InvalidDays(days.to_string()))
+            );
+        }
+
+        let Ok(Parsed::Run(parsed)) = parse_season_from(args(&["1..26"])) else {
+            panic!("expected arguments");
+        };
+        assert_eq!(parsed.days, 1..=25);
     }
 }
@@ -0,0 +1,160 @@
+//! Repeated timing of the parsing and solving phases of a solver.
+
+use std::hint;
+use std::time::{Duration, Instant};
+
+use crate::args::Parts;
+use crate::duration::Units;
+use crate::{print_rows, ParseError, Solver};
+
+/// Summary statistics of the durations of a phase over all measured runs.
+#[derive(Debug, PartialEq)]
+struct Stats {
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+    std_dev: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+
+        let len = samples.len();
+        let median = if len.is_multiple_of(2) {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        } else {
+            samples[len / 2]
+        };
+
+        let mean = samples.iter().sum::<Duration>() / len as u32;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / len as f64;
+
+        Self {
+            min: samples[0],
+            median,
+            mean,
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+pub(crate) struct Benchmark {
+    runs: usize,
+    warm_up: usize,
+    parse: Stats,
+    part_1: Option<Stats>,
+    part_2: Option<Stats>,
+}
+
+/// Parses and solves `input` for `runs` runs, after discarding a tenth as many warm-up runs.
+/// The parts of each run are solved with the input parsed in that run.
+pub(crate) fn bench<S: Solver>(
+    input: &str,
+    parts: Parts,
+    runs: usize,
+) -> Result<Benchmark, ParseError> {
+    let warm_up = (runs / 10).max(1);
+
+    let mut parse = Vec::with_capacity(runs);
+    let mut part_1 = Vec::with_capacity(runs);
+    let mut part_2 = Vec::with_capacity(runs);
+
+    for run in 0..warm_up + runs {
+        let start = Instant::now();
+        let parsed = S::parse(hint::black_box(input)).map_err(|error| error.locate(input))?;
+        let parse_elapsed = start.elapsed();
+
+        let part_1_elapsed = parts
+            .run_part_1
+            .then(|| time(|| hint::black_box(S::part_1(&parsed))));
+        let part_2_elapsed = parts
+            .run_part_2
+            .then(|| time(|| hint::black_box(S::part_2(&parsed))));
+
+        if run >= warm_up {
+            parse.push(parse_elapsed);
+            part_1.extend(part_1_elapsed);
+            part_2.extend(part_2_elapsed);
+        }
+    }
+
+    Ok(Benchmark {
+        runs,
+        warm_up,
+        parse: Stats::new(parse),
+        part_1: parts.run_part_1.then(|| Stats::new(part_1)),
+        part_2: parts.run_part_2.then(|| Stats::new(part_2)),
+    })
+}
+
+fn time<T>(phase: impl FnOnce() -> T) -> Duration {
+    let start = Instant::now();
+    phase();
+    start.elapsed()
+}
+
+pub(crate) fn print(benchmark: &Benchmark, units: Units) {
+    println!(
+        "Benchmark of {} (after {}):",
+        plural(benchmark.runs, "run"),
+        plural(benchmark.warm_up, "warm-up run"),
+    );
+    println!();
+
+    let mut table = vec![["Phase", "Min", "Median", "Mean", "Std. dev."]
+        .map(str::to_string)
+        .to_vec()];
+
+    let phases = [
+        ("Parse", Some(&benchmark.parse)),
+        ("Part 1", benchmark.part_1.as_ref()),
+        ("Part 2", benchmark.part_2.as_ref()),
+    ];
+
+    for (phase, stats) in phases {
+        if let Some(stats) = stats {
+            let durations = [stats.min, stats.median, stats.mean, stats.std_dev];
+            table.push(
+                [phase.to_string()]
+                    .into_iter()
+                    .chain(durations.map(|duration| units.format(duration)))
+                    .collect(),
+            );
+        }
+    }
+
+    print_rows(&table);
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{count} {noun}")
+    } else {
+        format!("{count} {noun}s")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis).to_vec();
+
+        assert_eq!(
+            Stats::new(samples),
+            Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_micros(2_500),
+                mean: Duration::from_micros(2_500),
+                std_dev: Duration::from_secs_f64(1.25_f64.sqrt() / 1_000.0),
+            }
+        );
+    }
+}
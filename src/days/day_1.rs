@@ -0,0 +1,111 @@
+use crate::{Answer, ParseError, Unsolved};
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+    type Output1 = u32;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }
+
+    fn part_1(input: &Self::Input) -> Answer<Self::Output1> {
+        input
+            .iter()
+            .map(|line| parse_line_part_1(line))
+            .sum::<Option<_>>()
+            .ok_or(Unsolved::NoSolution)
+    }
+
+    fn part_2(input: &Self::Input) -> Answer<Self::Output2> {
+        input
+            .iter()
+            .map(|line| parse_line_part_2(line))
+            .sum::<Option<_>>()
+            .ok_or(Unsolved::NoSolution)
+    }
+}
+
+/// The calibration value of `line`, or `None` if it contains no digit.
+fn parse_line_part_1(line: &str) -> Option<u32> {
+    let mut iter = line.chars().filter_map(|c| c.to_digit(10));
+
+    let first = iter.next()?;
+    let last = iter.next_back().unwrap_or(first);
+
+    Some(first * 10 + last)
+}
+
+/// The calibration value of `line`, or `None` if it contains no digit, spelled out or not.
+fn parse_line_part_2(line: &str) -> Option<usize> {
+    const NUMBER_STRINGS: [&str; 20] = [
+        "zero", "0", "one", "1", "two", "2", "three", "3", "four", "4", "five", "5", "six", "6",
+        "seven", "7", "eight", "8", "nine", "9",
+    ];
+
+    let mut first = None;
+    let mut last = None;
+
+    for (number_index, number_string) in NUMBER_STRINGS.iter().enumerate() {
+        for (current_index, _) in line.match_indices(number_string) {
+            if first.is_none_or(|(first_index, _)| current_index < first_index) {
+                first = Some((current_index, number_index / 2));
+            }
+
+            if last.is_none_or(|(last_index, _)| current_index > last_index) {
+                last = Some((current_index, number_index / 2));
+            }
+        }
+    }
+
+    Some(first?.1 * 10 + last?.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input_1() -> <Solver as crate::Solver>::Input {
+        let input = r"1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+        <Solver as crate::Solver>::parse(input).unwrap()
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(<Solver as crate::Solver>::part_1(&get_input_1()), Ok(142));
+        assert_eq!(
+            <Solver as crate::Solver>::part_1(&vec![String::from("abc")]),
+            Err(Unsolved::NoSolution)
+        );
+    }
+
+    fn get_input_2() -> <Solver as crate::Solver>::Input {
+        let input = r"two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+        <Solver as crate::Solver>::parse(input).unwrap()
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(<Solver as crate::Solver>::part_2(&get_input_2()), Ok(281));
+        assert_eq!(
+            <Solver as crate::Solver>::part_2(&vec![String::from("abc")]),
+            Err(Unsolved::NoSolution)
+        );
+    }
+}
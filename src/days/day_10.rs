@@ -0,0 +1,434 @@
+use crate::grid::BitGrid;
+use crate::parse::ParseError;
+use crate::{Answer, Unsolved};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Pipe {
+    V,
+    H,
+    L,
+    J,
+    T,
+    F,
+    G,
+    S,
+}
+
+impl TryFrom<char> for Pipe {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '|' => Ok(Pipe::V),
+            '-' => Ok(Pipe::H),
+            'L' => Ok(Pipe::L),
+            'J' => Ok(Pipe::J),
+            '7' => Ok(Pipe::T),
+            'F' => Ok(Pipe::F),
+            '.' => Ok(Pipe::G),
+            'S' => Ok(Pipe::S),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Pipe {
+    #[allow(unused)]
+    fn get_char(&self) -> char {
+        match *self {
+            Pipe::V => '│',
+            Pipe::H => '─',
+            Pipe::L => '└',
+            Pipe::J => '┘',
+            Pipe::T => '┐',
+            Pipe::F => '┌',
+            Pipe::G => '.',
+            Pipe::S => 'S',
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pos {
+    x: u64,
+    y: u64,
+}
+
+impl From<(usize, usize)> for Pos {
+    fn from(coords: (usize, usize)) -> Self {
+        Self {
+            x: coords.0 as u64,
+            y: coords.1 as u64,
+        }
+    }
+}
+
+impl From<Pos> for crate::grid::Pos {
+    fn from(pos: Pos) -> Self {
+        Self::new(pos.x as usize, pos.y as usize)
+    }
+}
+
+impl From<(u64, u64)> for Pos {
+    fn from(coords: (u64, u64)) -> Self {
+        Self {
+            x: coords.0,
+            y: coords.1,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid(Vec<Vec<Pipe>>);
+
+impl Grid {
+    pub fn get_start(&self) -> Option<Pos> {
+        for y in 0..self.0.len() {
+            for x in 0..self.0[y].len() {
+                if matches!(self.0[y][x], Pipe::S) {
+                    return Some((x, y).into());
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.0
+            .get(pos.y as usize)
+            .is_some_and(|row| (pos.x as usize) < row.len())
+    }
+
+    pub fn get(&self, pos: Pos) -> Pipe {
+        self.0[pos.y as usize][pos.x as usize]
+    }
+
+    /// The position after `curr` when coming from `prev`, or `None` if `curr` is not a pipe or
+    /// its other end points out of the grid.
+    pub fn get_next(&self, curr: Pos, prev: Pos) -> Option<Pos> {
+        let next: Pos = match self.get(curr) {
+            Pipe::V => (
+                curr.x,
+                if prev.y + 1 == curr.y {
+                    curr.y + 1
+                } else {
+                    curr.y.checked_sub(1)?
+                },
+            ),
+            Pipe::H => (
+                if prev.x + 1 == curr.x {
+                    curr.x + 1
+                } else {
+                    curr.x.checked_sub(1)?
+                },
+                curr.y,
+            ),
+            Pipe::L => {
+                if prev.x == curr.x {
+                    (curr.x + 1, curr.y)
+                } else {
+                    (curr.x, curr.y.checked_sub(1)?)
+                }
+            }
+            Pipe::J => {
+                if prev.x == curr.x {
+                    (curr.x.checked_sub(1)?, curr.y)
+                } else {
+                    (curr.x, curr.y.checked_sub(1)?)
+                }
+            }
+            Pipe::T => {
+                if prev.x == curr.x {
+                    (curr.x.checked_sub(1)?, curr.y)
+                } else {
+                    (curr.x, curr.y + 1)
+                }
+            }
+            Pipe::F => {
+                if prev.x == curr.x {
+                    (curr.x + 1, curr.y)
+                } else {
+                    (curr.x, curr.y + 1)
+                }
+            }
+            Pipe::G | Pipe::S => return None,
+        }
+        .into();
+
+        self.contains(next).then_some(next)
+    }
+
+    pub fn are_connected(&self, start_pos: Pos, cell_pos: Pos) -> bool {
+        match self.get(cell_pos) {
+            Pipe::V => cell_pos.x == start_pos.x,
+            Pipe::H => cell_pos.y == start_pos.y,
+            Pipe::L => {
+                (cell_pos.x == start_pos.x && cell_pos.y == start_pos.y + 1)
+                    || (cell_pos.x + 1 == start_pos.x && cell_pos.y == start_pos.y)
+            }
+            Pipe::J => {
+                (cell_pos.x == start_pos.x && cell_pos.y == start_pos.y + 1)
+                    || (cell_pos.x == start_pos.x + 1 && cell_pos.y == start_pos.y)
+            }
+            Pipe::T => {
+                (cell_pos.x == start_pos.x && cell_pos.y + 1 == start_pos.y)
+                    || (cell_pos.x == start_pos.x + 1 && cell_pos.y == start_pos.y)
+            }
+            Pipe::F => {
+                (cell_pos.x == start_pos.x && cell_pos.y + 1 == start_pos.y)
+                    || (cell_pos.x + 1 == start_pos.x && cell_pos.y == start_pos.y)
+            }
+            Pipe::G => false,
+            Pipe::S => false,
+        }
+    }
+
+    #[allow(unused)]
+    fn print(&self) {
+        for y in 0..self.0.len() {
+            for x in 0..self.0[y].len() {
+                let pos = Pos::from((x, y));
+                print!("{}", self.get(pos).get_char());
+            }
+            println!();
+        }
+    }
+}
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 10;
+
+    type Input = Grid;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = crate::Grid::<Pipe>::parse(input)?;
+
+        Ok(Grid(grid.rows().map(<[Pipe]>::to_vec).collect()))
+    }
+
+    fn part_1(grid: &Self::Input) -> Answer<Self::Output1> {
+        let tiles = find_loop(grid).ok_or(Unsolved::NoSolution)?;
+
+        Ok(tiles.len() as u32 / 2)
+    }
+
+    fn part_2(grid: &Self::Input) -> Answer<Self::Output2> {
+        let simplified = get_simplified_grid(grid).ok_or(Unsolved::NoSolution)?;
+        let inflated = inflate(&simplified);
+
+        // Ground that is connected to the edge of the inflated grid is outside of the loop.
+        let inflated = crate::Grid::from_iter(inflated.0);
+        let components = inflated.components(|dir| matches!(dir, Pipe::G));
+        let outside: Vec<_> = components
+            .regions()
+            .iter()
+            .map(|region| {
+                let (min, max) = region.bounding_box();
+                min.x == 0
+                    || min.y == 0
+                    || max.x == inflated.width() - 1
+                    || max.y == inflated.height() - 1
+            })
+            .collect();
+
+        let mut num_enclosed = 0;
+
+        for y in 0..grid.0.len() {
+            for x in 0..grid.0[y].len() {
+                let label = components.label(crate::grid::Pos::new(x * 2, y * 2));
+                if matches!(simplified.get((x, y).into()), Pipe::G)
+                    && label.is_some_and(|label| !outside[label])
+                {
+                    num_enclosed += 1;
+                }
+            }
+        }
+
+        Ok(num_enclosed)
+    }
+}
+
+/// The tiles of the loop through the start, beginning with the start, or `None` if there is
+/// no start or no loop through it.
+fn find_loop(grid: &Grid) -> Option<Vec<Pos>> {
+    let start = grid.get_start()?;
+
+    let next_steps = [
+        Some((start.x + 1, start.y).into()),
+        Some((start.x, start.y + 1).into()),
+        start.x.checked_sub(1).map(|x| (x, start.y).into()),
+        start.y.checked_sub(1).map(|y| (start.x, y).into()),
+    ]
+    .into_iter()
+    .flatten();
+
+    for step in next_steps {
+        if !grid.contains(step) || !grid.are_connected(start, step) {
+            continue;
+        }
+
+        let mut tiles = vec![start, step];
+        let mut prev = start;
+        let mut curr = step;
+
+        // Every pipe has two ends, so as long as each step enters the next pipe through one of
+        // them, the walk either returns to the start or reaches a dead end.
+        while let Some(next) = grid.get_next(curr, prev) {
+            if matches!(grid.get(next), Pipe::S) {
+                return Some(tiles);
+            }
+
+            if !grid.are_connected(curr, next) {
+                break;
+            }
+
+            tiles.push(next);
+            prev = curr;
+            curr = next;
+        }
+    }
+
+    None
+}
+
+/// A copy of the grid with every tile that is not part of the loop replaced by ground.
+fn get_simplified_grid(grid: &Grid) -> Option<Grid> {
+    let tiles = find_loop(grid)?;
+
+    let width = grid.0.first().map_or(0, Vec::len);
+    let mut loop_tiles = BitGrid::new(width, grid.0.len());
+    for &pos in &tiles {
+        loop_tiles.insert(pos.into());
+    }
+
+    let mut simplified = Grid(vec![]);
+
+    for y in 0..grid.0.len() {
+        simplified.0.push(vec![]);
+
+        for x in 0..grid.0[y].len() {
+            if loop_tiles.contains(crate::grid::Pos::new(x, y)) {
+                simplified.0[y].push(grid.get((x, y).into()));
+            } else {
+                simplified.0[y].push(Pipe::G);
+            }
+        }
+    }
+
+    Some(simplified)
+}
+
+fn inflate(grid: &Grid) -> Grid {
+    let mut inflated = Grid(vec![]);
+
+    for y in 0..grid.0.len() {
+        let mut new_row_1 = vec![];
+
+        for x in 0..grid.0[y].len() {
+            match grid.get((x, y).into()) {
+                d @ Pipe::H | d @ Pipe::L | d @ Pipe::F => {
+                    new_row_1.push(d);
+                    new_row_1.push(Pipe::H);
+                }
+                Pipe::S => {
+                    new_row_1.push(Pipe::S);
+                    new_row_1.push(Pipe::S);
+                }
+                d => {
+                    new_row_1.push(d);
+                    new_row_1.push(Pipe::G);
+                }
+            }
+        }
+
+        let mut new_row_2 = vec![];
+        for d in new_row_1.iter() {
+            match d {
+                Pipe::F | Pipe::T | Pipe::V => new_row_2.push(Pipe::V),
+                Pipe::S => new_row_2.push(Pipe::S),
+                _ => new_row_2.push(Pipe::G),
+            }
+        }
+
+        inflated.0.push(new_row_1);
+        inflated.0.push(new_row_2);
+    }
+
+    inflated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> <Solver as crate::Solver>::Input {
+        Grid(vec![
+            vec![Pipe::G, Pipe::G, Pipe::F, Pipe::T, Pipe::G],
+            vec![Pipe::G, Pipe::F, Pipe::J, Pipe::V, Pipe::G],
+            vec![Pipe::S, Pipe::J, Pipe::G, Pipe::L, Pipe::T],
+            vec![Pipe::V, Pipe::F, Pipe::H, Pipe::H, Pipe::J],
+            vec![Pipe::L, Pipe::J, Pipe::G, Pipe::G, Pipe::G],
+        ])
+    }
+
+    #[test]
+    fn parsing() {
+        let input = r"..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...";
+
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
+        assert!(<Solver as crate::Solver>::parse(".S.\n.|").is_err());
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(<Solver as crate::Solver>::part_1(&get_input()), Ok(8));
+    }
+
+    fn get_input_2() -> <Solver as crate::Solver>::Input {
+        <Solver as crate::Solver>::parse(
+            r"FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(<Solver as crate::Solver>::part_2(&get_input_2()), Ok(10));
+    }
+
+    #[test]
+    fn no_loop() {
+        for input in ["...\n...", "S", "SL", "S\n7", "S-\n..", ".|.\n-S-\n.|."] {
+            let input = <Solver as crate::Solver>::parse(input).unwrap();
+
+            assert_eq!(
+                <Solver as crate::Solver>::part_1(&input),
+                Err(Unsolved::NoSolution)
+            );
+            assert_eq!(
+                <Solver as crate::Solver>::part_2(&input),
+                Err(Unsolved::NoSolution)
+            );
+        }
+    }
+}
@@ -0,0 +1,298 @@
+use crate::parse::{self, ParseError};
+use crate::{Answer, Unsolved};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Status {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl TryFrom<char> for Status {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Status::Operational),
+            '#' => Ok(Status::Damaged),
+            '?' => Ok(Status::Unknown),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Record {
+    springs: Vec<Status>,
+    groups: Vec<usize>,
+}
+
+impl Record {
+    pub fn get_num_arrangements(&self) -> usize {
+        if !self.is_valid() {
+            0
+        } else if self.is_complete() {
+            1
+        } else if let Some(first_index) = self
+            .springs
+            .iter()
+            .position(|status| matches!(status, Status::Unknown))
+        {
+            let mut clone_a = self.clone();
+            clone_a.springs[first_index] = Status::Operational;
+
+            let mut clone_b = self.clone();
+            clone_b.springs[first_index] = Status::Damaged;
+
+            clone_a.get_num_arrangements() + clone_b.get_num_arrangements()
+        } else {
+            0
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        if self
+            .springs
+            .iter()
+            .any(|status| !matches!(status, Status::Unknown))
+        {
+            return true;
+        }
+
+        let splits: Vec<_> = self
+            .springs
+            .split(|status| !matches!(status, Status::Damaged))
+            .filter(|slice| !slice.is_empty())
+            .collect();
+        if splits.len() > self.groups.len() {
+            false
+        } else {
+            splits
+                .iter()
+                .zip(self.groups.iter())
+                .all(|(split, group)| split.len() <= *group)
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        if self
+            .springs
+            .iter()
+            .any(|status| matches!(status, Status::Unknown))
+        {
+            return false;
+        }
+
+        let splits: Vec<_> = self
+            .springs
+            .split(|status| !matches!(status, Status::Damaged))
+            .filter(|slice| !slice.is_empty())
+            .collect();
+        if splits.len() != self.groups.len() {
+            false
+        } else {
+            splits
+                .iter()
+                .zip(self.groups.iter())
+                .all(|(split, group)| split.len() == *group)
+        }
+    }
+}
+
+impl TryFrom<&str> for Record {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let (springs, groups) = parse::split_once(line, " ")?;
+
+        Ok(Self {
+            springs: parse::chars(springs)?,
+            groups: groups
+                .split(',')
+                .map(parse::number)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 12;
+
+    type Input = Vec<Record>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(Record::try_from).collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Answer<Self::Output1> {
+        Ok(input
+            .iter()
+            .map(|record| record.get_num_arrangements())
+            .sum())
+    }
+
+    fn part_2(_input: &Self::Input) -> Answer<Self::Output2> {
+        Err(Unsolved::NotImplemented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> <Solver as crate::Solver>::Input {
+        vec![
+            Record {
+                springs: vec![
+                    Status::Unknown,
+                    Status::Unknown,
+                    Status::Unknown,
+                    Status::Operational,
+                    Status::Damaged,
+                    Status::Damaged,
+                    Status::Damaged,
+                ],
+                groups: vec![1, 1, 3],
+            },
+            Record {
+                springs: vec![
+                    Status::Operational,
+                    Status::Unknown,
+                    Status::Unknown,
+                    Status::Operational,
+                    Status::Operational,
+                    Status::Unknown,
+                    Status::Unknown,
+                    Status::Operational,
+                    Status::Operational,
+                    Status::Operational,
+                    Status::Unknown,
+                    Status::Damaged,
+                    Status::Damaged,
+                    Status::Operational,
+                ],
+                groups: vec![1, 1, 3],
+            },
+            Record {
+                springs: vec![
+                    Status::Unknown,
+                    Status::Damaged,
+                    Status::Unknown,
+                    Status::Damaged,
+                    Status::Unknown,
+                    Status::Damaged,
+                    Status::Unknown,
+                    Status::Damaged,
+                    Status::Unknown,
+                    Status::Damaged,
+                    Status::Unknown,
+                    Status::Damaged,
+                    Status::Unknown,
+                    Status::Damaged,
+                    Status::Unknown,
+                ],
+                groups: vec![1, 3, 1, 6],
+            },
+            Record {
+                springs: vec![
+                    Status::Unknown,
+                    Status::Unknown,
+                    Status::Unknown,
+                    Status::Unknown,
+                    Status::Operational,
+                    Status::Damaged,
+                    Status::Operational,
+                    Status::Operational,
+                    Status::Operational,
+                    Status::Damaged,
+                    Status::Operational,
+                    Status::Operational,
+                    Status::Operational,
+                ],
+                groups: vec![4, 1, 1],
+            },
+            Record {
+                springs: vec![
+                    Status::Unknown,
+                    Status::Unknown,
+                    Status::Unknown,
+                    Status::Unknown,
+                    Status::Operational,
+                    Status::Damaged,
+                    Status::Damaged,
+                    Status::Damaged,
+                    Status::Damaged,
+                    Status::Damaged,
+                    Status::Damaged,
+                    Status::Operational,
+                    Status::Operational,
+                    Status::Damaged,
+                    Status::Damaged,
+                    Status::Damaged,
+                    Status::Damaged,
+                    Status::Damaged,
+                    Status::Operational,
+                ],
+                groups: vec![1, 6, 5],
+            },
+            Record {
+                springs: vec![
+                    Status::Unknown,
+                    Status::Damaged,
+                    Status::Damaged,
+                    Status::Damaged,
+                    Status::Unknown,
+                    Status::Unknown,
+                    Status::Unknown,
+                    Status::Unknown,
+                    Status::Unknown,
+                    Status::Unknown,
+                    Status::Unknown,
+                    Status::Unknown,
+                ],
+                groups: vec![3, 2, 1],
+            },
+        ]
+    }
+
+    #[test]
+    fn parsing() {
+        let input = r"???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
+    }
+
+    #[test]
+    fn parsing_invalid_spring() {
+        let input = "???.### 1,1,3\n.??.!??...?##. 1,1,3";
+        let error = <Solver as crate::Solver>::parse(input)
+            .unwrap_err()
+            .locate(input);
+
+        assert_eq!((error.line(), error.column()), (Some(2), Some(5)));
+        assert_eq!(error.text(), "!");
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(<Solver as crate::Solver>::part_1(&get_input()), Ok(21));
+    }
+
+    #[test]
+    #[ignore = "not yet implemented"]
+    #[allow(unreachable_code, clippy::diverging_sub_expression)]
+    fn part_2() {
+        assert_eq!(<Solver as crate::Solver>::part_2(&get_input()), todo!());
+    }
+}
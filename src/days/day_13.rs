@@ -0,0 +1,125 @@
+use crate::parse::{self, ParseError};
+use crate::{Answer, Grid};
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Grid<char>>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::Sections::new(input).repeat("a pattern", Grid::parse)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer<Self::Output1> {
+        Ok(input
+            .iter()
+            .map(|pattern| get_pattern_score(pattern, 0))
+            .sum())
+    }
+
+    fn part_2(input: &Self::Input) -> Answer<Self::Output2> {
+        Ok(input
+            .iter()
+            .map(|pattern| get_pattern_score(pattern, 1))
+            .sum())
+    }
+}
+
+/// Scores the line of reflection at which exactly `num_smudges` cells differ from their
+/// reflection.
+fn get_pattern_score(pattern: &Grid<char>, num_smudges: usize) -> usize {
+    // Columns are handled as the rows of the transposed pattern.
+    let y_score = rows_reflect_at(pattern, num_smudges);
+    let x_score = rows_reflect_at(&pattern.transpose(), num_smudges);
+
+    y_score * 100 + x_score
+}
+
+fn rows_reflect_at(pattern: &Grid<char>, num_smudges: usize) -> usize {
+    let rows: Vec<_> = pattern.rows().collect();
+
+    (1..rows.len())
+        .filter(|&split| {
+            let num_mismatch: usize = rows[..split]
+                .iter()
+                .rev()
+                .zip(&rows[split..])
+                .map(|(backward, forward)| {
+                    backward
+                        .iter()
+                        .zip(forward.iter())
+                        .filter(|(a, b)| a != b)
+                        .count()
+                })
+                .sum();
+
+            num_mismatch == num_smudges
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> <Solver as crate::Solver>::Input {
+        vec![
+            Grid::from_iter([
+                vec!['#', '.', '#', '#', '.', '.', '#', '#', '.'],
+                vec!['.', '.', '#', '.', '#', '#', '.', '#', '.'],
+                vec!['#', '#', '.', '.', '.', '.', '.', '.', '#'],
+                vec!['#', '#', '.', '.', '.', '.', '.', '.', '#'],
+                vec!['.', '.', '#', '.', '#', '#', '.', '#', '.'],
+                vec!['.', '.', '#', '#', '.', '.', '#', '#', '.'],
+                vec!['#', '.', '#', '.', '#', '#', '.', '#', '.'],
+            ]),
+            Grid::from_iter([
+                vec!['#', '.', '.', '.', '#', '#', '.', '.', '#'],
+                vec!['#', '.', '.', '.', '.', '#', '.', '.', '#'],
+                vec!['.', '.', '#', '#', '.', '.', '#', '#', '#'],
+                vec!['#', '#', '#', '#', '#', '.', '#', '#', '.'],
+                vec!['#', '#', '#', '#', '#', '.', '#', '#', '.'],
+                vec!['.', '.', '#', '#', '.', '.', '#', '#', '#'],
+                vec!['#', '.', '.', '.', '.', '#', '.', '.', '#'],
+            ]),
+        ]
+    }
+
+    #[test]
+    fn parsing() {
+        let input = r"#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(<Solver as crate::Solver>::part_1(&get_input()), Ok(405));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(<Solver as crate::Solver>::part_2(&get_input()), Ok(400));
+    }
+}
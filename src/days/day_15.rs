@@ -0,0 +1,228 @@
+use crate::parse::{self, ParseError};
+use crate::Answer;
+
+#[derive(Debug, PartialEq)]
+struct Step {
+    /// The step as written, which is what the HASH algorithm is run on in part 1.
+    text: String,
+    label: String,
+    operation: char,
+    focal_length: usize,
+}
+
+impl TryFrom<&str> for Step {
+    type Error = ParseError;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        let operation_index = string
+            .find(['-', '='])
+            .ok_or_else(|| ParseError::new(string, "expected '-' or '='"))?;
+
+        let label = string[0..operation_index].to_owned();
+
+        let operation = string[operation_index..].chars().next().unwrap();
+
+        let focal_length = if operation == '=' {
+            parse::number(&string[operation_index + 1..])?
+        } else {
+            0
+        };
+
+        Ok(Self {
+            text: string.to_owned(),
+            label,
+            operation,
+            focal_length,
+        })
+    }
+}
+
+struct Boxes(Vec<Vec<(String, usize)>>);
+
+impl Boxes {
+    pub fn new() -> Self {
+        Self([vec![]].into_iter().cycle().take(256).collect())
+    }
+
+    pub fn apply(self, step: &Step) -> Self {
+        match step.operation {
+            '=' => self.add(step),
+            '-' => self.remove(step),
+            _ => panic!(),
+        }
+    }
+
+    pub fn get_focusing_power(&self) -> usize {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(box_index, boxes)| {
+                (box_index + 1)
+                    * boxes
+                        .iter()
+                        .enumerate()
+                        .map(|(lens_index, (_, focal_length))| (lens_index + 1) * focal_length)
+                        .sum::<usize>()
+            })
+            .sum()
+    }
+
+    fn add(mut self, step: &Step) -> Self {
+        let hash = run_hash(&step.label);
+
+        if let Some(lens_box) = self.0[hash]
+            .iter_mut()
+            .find(|lens_box| lens_box.0 == step.label)
+        {
+            lens_box.1 = step.focal_length;
+        } else {
+            self.0[hash].push((step.label.to_owned(), step.focal_length))
+        }
+
+        self
+    }
+
+    fn remove(mut self, step: &Step) -> Self {
+        let hash = run_hash(&step.label);
+
+        if let Some(index) = self.0[hash]
+            .iter()
+            .position(|lens_box| lens_box.0 == step.label)
+        {
+            let _ = self.0[hash].remove(index);
+        }
+
+        self
+    }
+}
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 15;
+
+    type Input = Vec<Step>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .split(',')
+            .map(|string| Step::try_from(string.trim()))
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Answer<Self::Output1> {
+        Ok(input.iter().map(|step| run_hash(&step.text)).sum())
+    }
+
+    fn part_2(input: &Self::Input) -> Answer<Self::Output2> {
+        Ok(input
+            .iter()
+            .fold(Boxes::new(), |boxes, step| boxes.apply(step))
+            .get_focusing_power())
+    }
+}
+
+fn run_hash(input: &str) -> usize {
+    input
+        .chars()
+        .fold(0, |acc, c| (acc + c as usize) * 17 % 256)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> <Solver as crate::Solver>::Input {
+        vec![
+            Step {
+                text: String::from("rn=1"),
+                label: String::from("rn"),
+                operation: '=',
+                focal_length: 1,
+            },
+            Step {
+                text: String::from("cm-"),
+                label: String::from("cm"),
+                operation: '-',
+                focal_length: 0,
+            },
+            Step {
+                text: String::from("qp=3"),
+                label: String::from("qp"),
+                operation: '=',
+                focal_length: 3,
+            },
+            Step {
+                text: String::from("cm=2"),
+                label: String::from("cm"),
+                operation: '=',
+                focal_length: 2,
+            },
+            Step {
+                text: String::from("qp-"),
+                label: String::from("qp"),
+                operation: '-',
+                focal_length: 0,
+            },
+            Step {
+                text: String::from("pc=4"),
+                label: String::from("pc"),
+                operation: '=',
+                focal_length: 4,
+            },
+            Step {
+                text: String::from("ot=9"),
+                label: String::from("ot"),
+                operation: '=',
+                focal_length: 9,
+            },
+            Step {
+                text: String::from("ab=5"),
+                label: String::from("ab"),
+                operation: '=',
+                focal_length: 5,
+            },
+            Step {
+                text: String::from("pc-"),
+                label: String::from("pc"),
+                operation: '-',
+                focal_length: 0,
+            },
+            Step {
+                text: String::from("pc=6"),
+                label: String::from("pc"),
+                operation: '=',
+                focal_length: 6,
+            },
+            Step {
+                text: String::from("ot=7"),
+                label: String::from("ot"),
+                operation: '=',
+                focal_length: 7,
+            },
+        ]
+    }
+
+    #[test]
+    fn parsing() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
+
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
+        assert!(<Solver as crate::Solver>::parse("rn=1,cm").is_err());
+        assert!(<Solver as crate::Solver>::parse("rn=x").is_err());
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(<Solver as crate::Solver>::part_1(&get_input()), Ok(1320));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(<Solver as crate::Solver>::part_2(&get_input()), Ok(145));
+    }
+}
@@ -0,0 +1,326 @@
+use crate::grid::{DirGrid, Pos};
+use crate::parse::ParseError;
+use crate::{Answer, Dir, Unsolved};
+
+fn update_dir(dir: Dir, contents: Contents) -> Vec<Dir> {
+    match contents {
+        Contents::Empty => vec![dir],
+        Contents::Acute => vec![match dir {
+            Dir::N => Dir::E,
+            Dir::E => Dir::N,
+            Dir::S => Dir::W,
+            Dir::W => Dir::S,
+        }],
+        Contents::Grave => vec![match dir {
+            Dir::N => Dir::W,
+            Dir::E => Dir::S,
+            Dir::S => Dir::E,
+            Dir::W => Dir::N,
+        }],
+        Contents::Hor => match dir {
+            Dir::N => vec![Dir::W, Dir::E],
+            Dir::E => vec![Dir::E],
+            Dir::S => vec![Dir::W, Dir::E],
+            Dir::W => vec![Dir::W],
+        },
+        Contents::Ver => match dir {
+            Dir::N => vec![Dir::N],
+            Dir::E => vec![Dir::N, Dir::S],
+            Dir::S => vec![Dir::S],
+            Dir::W => vec![Dir::N, Dir::S],
+        },
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Beam {
+    pos: Pos,
+    dir: Dir,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Contents {
+    Empty,
+    Acute,
+    Grave,
+    Hor,
+    Ver,
+}
+
+impl TryFrom<char> for Contents {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Contents::Empty),
+            '/' => Ok(Contents::Acute),
+            '\\' => Ok(Contents::Grave),
+            '-' => Ok(Contents::Hor),
+            '|' => Ok(Contents::Ver),
+            _ => Err(()),
+        }
+    }
+}
+
+type Grid = crate::Grid<Contents>;
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 16;
+
+    type Input = Grid;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_1(grid: &Self::Input) -> Answer<Self::Output1> {
+        if grid.width() == 0 || grid.height() == 0 {
+            return Err(Unsolved::NoSolution);
+        }
+
+        Ok(energize_grid(
+            grid,
+            Beam {
+                pos: Pos { x: 0, y: 0 },
+                dir: Dir::E,
+            },
+            &mut DirGrid::new(grid.width(), grid.height()),
+        ))
+    }
+
+    fn part_2(grid: &Self::Input) -> Answer<Self::Output2> {
+        let mut handled = DirGrid::new(grid.width(), grid.height());
+
+        (0..grid.height())
+            .map(|y| Beam {
+                pos: Pos { x: 0, y },
+                dir: Dir::E,
+            })
+            .chain((0..grid.height()).map(|y| Beam {
+                pos: Pos {
+                    x: grid.width() - 1,
+                    y,
+                },
+                dir: Dir::W,
+            }))
+            .chain((0..grid.width()).map(|x| Beam {
+                pos: Pos { x, y: 0 },
+                dir: Dir::S,
+            }))
+            .chain((0..grid.width()).map(|x| Beam {
+                pos: Pos {
+                    x,
+                    y: grid.height() - 1,
+                },
+                dir: Dir::N,
+            }))
+            .map(|beam| energize_grid(grid, beam, &mut handled))
+            .max()
+            .ok_or(Unsolved::NoSolution)
+    }
+}
+
+/// Follows the beam through the grid and counts the energized cells. `handled` records the
+/// direction in which beams have entered each cell, and is cleared before use so that it can be
+/// shared between calls.
+fn energize_grid(grid: &Grid, initial_beam: Beam, handled: &mut DirGrid) -> usize {
+    handled.clear();
+
+    let mut beams = vec![initial_beam];
+
+    while let Some(beam) = beams.pop() {
+        if !handled.insert(beam.pos, beam.dir) {
+            continue;
+        }
+
+        let Pos { x, y } = beam.pos;
+
+        beams.extend(
+            update_dir(beam.dir, *grid.get(x, y))
+                .into_iter()
+                .filter_map(|dir| {
+                    beam.pos
+                        .checked_apply(dir, grid)
+                        .map(|pos| Beam { pos, dir })
+                }),
+        );
+    }
+
+    handled.cells().count_ones()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> <Solver as crate::Solver>::Input {
+        Grid::from_iter([
+            [
+                Contents::Empty,
+                Contents::Ver,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Grave,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+            ],
+            [
+                Contents::Ver,
+                Contents::Empty,
+                Contents::Hor,
+                Contents::Empty,
+                Contents::Grave,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+            ],
+            [
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Ver,
+                Contents::Hor,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+            ],
+            [
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Ver,
+                Contents::Empty,
+            ],
+            [
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+            ],
+            [
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Grave,
+            ],
+            [
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Acute,
+                Contents::Empty,
+                Contents::Grave,
+                Contents::Grave,
+                Contents::Empty,
+                Contents::Empty,
+            ],
+            [
+                Contents::Empty,
+                Contents::Hor,
+                Contents::Empty,
+                Contents::Hor,
+                Contents::Acute,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Ver,
+                Contents::Empty,
+                Contents::Empty,
+            ],
+            [
+                Contents::Empty,
+                Contents::Ver,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Hor,
+                Contents::Ver,
+                Contents::Empty,
+                Contents::Grave,
+            ],
+            [
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Acute,
+                Contents::Acute,
+                Contents::Empty,
+                Contents::Ver,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+                Contents::Empty,
+            ],
+        ])
+    }
+
+    #[test]
+    fn parsing() {
+        let input = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(<Solver as crate::Solver>::part_1(&get_input()), Ok(46));
+    }
+
+    #[test]
+    fn empty() {
+        let input = <Solver as crate::Solver>::parse("").unwrap();
+
+        assert_eq!(
+            <Solver as crate::Solver>::part_1(&input),
+            Err(Unsolved::NoSolution)
+        );
+        assert_eq!(
+            <Solver as crate::Solver>::part_2(&input),
+            Err(Unsolved::NoSolution)
+        );
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(<Solver as crate::Solver>::part_2(&get_input()), Ok(51));
+    }
+}
@@ -0,0 +1,163 @@
+use crate::grid::Pos;
+use crate::parse::ParseError;
+use crate::{search, Answer, Dir, Unsolved};
+
+type Grid = crate::Grid<u32>;
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 17;
+
+    type Input = Grid;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::try_parse_with(input, |c| c.to_digit(10).ok_or(()))
+    }
+
+    fn part_1(grid: &Self::Input) -> Answer<Self::Output1> {
+        get_min_heat_loss(grid, 1, 3)
+    }
+
+    fn part_2(grid: &Self::Input) -> Answer<Self::Output2> {
+        get_min_heat_loss(grid, 4, 10)
+    }
+}
+
+/// Finds the least heat loss on a path from the top-left to the bottom-right block, where the
+/// crucible moves at least `min_run` and at most `max_run` blocks in a straight line before it
+/// has to turn.
+///
+/// A state is a block and the direction of the straight run that ended there. Every step of the
+/// search is a complete run after a turn, so the search does not need to count steps.
+fn get_min_heat_loss(grid: &Grid, min_run: usize, max_run: usize) -> Answer<u64> {
+    if grid.width() == 0 || grid.height() == 0 {
+        return Err(Unsolved::NoSolution);
+    }
+
+    let start = Pos::new(0, 0);
+    let end = Pos::new(grid.width() - 1, grid.height() - 1);
+
+    // Turning from east or south allows the first run to go either way.
+    let search = search::dijkstra(
+        [(start, Dir::E), (start, Dir::S)],
+        |&(pos, dir)| {
+            [dir.left(), dir.right()]
+                .into_iter()
+                .flat_map(move |dir| get_runs(grid, pos, dir, min_run, max_run))
+                .collect::<Vec<_>>()
+        },
+        |&(pos, _)| pos == end,
+    );
+
+    search
+        .goal()
+        .map(|(_, heat_loss)| heat_loss)
+        .ok_or(Unsolved::NoSolution)
+}
+
+/// The blocks where a run from `pos` in `dir` can end, with the heat lost on the way.
+fn get_runs(
+    grid: &Grid,
+    mut pos: Pos,
+    dir: Dir,
+    min_run: usize,
+    max_run: usize,
+) -> Vec<((Pos, Dir), u64)> {
+    let mut runs = vec![];
+    let mut heat_loss = 0;
+
+    for length in 1..=max_run {
+        let Some(next) = pos.checked_apply(dir, grid) else {
+            break;
+        };
+
+        pos = next;
+        heat_loss += u64::from(*grid.get(pos.x, pos.y));
+
+        if length >= min_run {
+            runs.push(((pos, dir), heat_loss));
+        }
+    }
+
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> <Solver as crate::Solver>::Input {
+        Grid::from_iter([
+            [2, 4, 1, 3, 4, 3, 2, 3, 1, 1, 3, 2, 3],
+            [3, 2, 1, 5, 4, 5, 3, 5, 3, 5, 6, 2, 3],
+            [3, 2, 5, 5, 2, 4, 5, 6, 5, 4, 2, 5, 4],
+            [3, 4, 4, 6, 5, 8, 5, 8, 4, 5, 4, 5, 2],
+            [4, 5, 4, 6, 6, 5, 7, 8, 6, 7, 5, 3, 6],
+            [1, 4, 3, 8, 5, 9, 8, 7, 9, 8, 4, 5, 4],
+            [4, 4, 5, 7, 8, 7, 6, 9, 8, 7, 7, 6, 6],
+            [3, 6, 3, 7, 8, 7, 7, 9, 7, 9, 6, 5, 3],
+            [4, 6, 5, 4, 9, 6, 7, 9, 8, 6, 8, 8, 7],
+            [4, 5, 6, 4, 6, 7, 9, 9, 8, 6, 4, 5, 3],
+            [1, 2, 2, 4, 6, 8, 6, 8, 6, 5, 5, 6, 3],
+            [2, 5, 4, 6, 5, 4, 8, 8, 8, 7, 7, 3, 5],
+            [4, 3, 2, 2, 6, 7, 4, 6, 5, 5, 5, 3, 3],
+        ])
+    }
+
+    fn get_input_2() -> <Solver as crate::Solver>::Input {
+        Grid::from_iter([
+            [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+            [9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 1],
+            [9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 1],
+            [9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 1],
+            [9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 1],
+        ])
+    }
+
+    #[test]
+    fn parsing() {
+        let input = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
+        assert!(<Solver as crate::Solver>::parse("24\n3x").is_err());
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(<Solver as crate::Solver>::part_1(&get_input()), Ok(102));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(<Solver as crate::Solver>::part_2(&get_input()), Ok(94));
+        assert_eq!(<Solver as crate::Solver>::part_2(&get_input_2()), Ok(71));
+    }
+
+    #[test]
+    fn empty() {
+        let input = <Solver as crate::Solver>::parse("").unwrap();
+
+        assert_eq!(
+            <Solver as crate::Solver>::part_1(&input),
+            Err(Unsolved::NoSolution)
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use crate::grid::{IPos, Pos, Region};
+use crate::parse::{self, ParseError};
+use crate::{Answer, Dir, Unsolved};
+
+#[derive(Debug, PartialEq)]
+struct Instruction {
+    dir: Dir,
+    steps: usize,
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let mut iter = line.split_whitespace();
+
+        let dir = parse::next(&mut iter, line, "a direction")?;
+        let dir = parse::char(dir)
+            .ok()
+            .and_then(Dir::from_udlr)
+            .ok_or_else(|| ParseError::new(dir, "expected 'U', 'R', 'D' or 'L'"))?;
+
+        let steps = parse::number(parse::next(&mut iter, line, "a number of steps")?)?;
+
+        Ok(Self { dir, steps })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum State {
+    Ground,
+    Edge,
+}
+
+type Grid = crate::Grid<State>;
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 18;
+
+    type Input = Vec<Instruction>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(Instruction::try_from).collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Answer<Self::Output1> {
+        let positions = trace_instructions(input);
+        let (min_x, min_y, max_x, max_y) = get_grid_size(&positions).ok_or(Unsolved::NoSolution)?;
+
+        let mut grid = Grid::new(
+            (max_x - min_x) as usize + 1,
+            (max_y - min_y) as usize + 1,
+            State::Ground,
+        );
+
+        let positions =
+            translate_positions(positions, IPos::new(min_x, min_y)).ok_or(Unsolved::NoSolution)?;
+        for position in positions {
+            *grid.get_mut(position.x, position.y) = State::Edge;
+        }
+
+        // Cells that are not enclosed by the trench are connected to the edge of the grid.
+        let components = grid.components(|state| *state != State::Edge);
+        let num_exterior: usize = components
+            .regions()
+            .iter()
+            .filter(|region| {
+                let (min, max) = region.bounding_box();
+                min.x == 0 || min.y == 0 || max.x == grid.width() - 1 || max.y == grid.height() - 1
+            })
+            .map(Region::size)
+            .sum();
+
+        Ok(grid.width() * grid.height() - num_exterior)
+    }
+
+    fn part_2(_input: &Self::Input) -> Answer<Self::Output2> {
+        Err(Unsolved::NotImplemented)
+    }
+}
+
+fn trace_instructions(instructions: &[Instruction]) -> Vec<IPos> {
+    let mut positions = vec![];
+    let mut cur = IPos::default();
+
+    for instr in instructions {
+        for _ in 0..instr.steps {
+            cur = cur.apply(instr.dir, 1);
+            positions.push(cur);
+        }
+    }
+
+    positions
+}
+
+/// Moves `positions` so that `min` becomes the origin, or returns `None` if a position would
+/// end up left of or above it.
+fn translate_positions(positions: Vec<IPos>, min: IPos) -> Option<Vec<Pos>> {
+    positions
+        .into_iter()
+        .map(|pos| Pos::try_from(pos - min).ok())
+        .collect()
+}
+
+/// The smallest and largest coordinates of `positions`, or `None` if there are none.
+fn get_grid_size(positions: &[IPos]) -> Option<(isize, isize, isize, isize)> {
+    let first = positions.first()?;
+
+    Some(positions.iter().fold(
+        (first.x, first.y, first.x, first.y),
+        |(min_x, min_y, max_x, max_y), &IPos { x, y }| {
+            (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> <Solver as crate::Solver>::Input {
+        vec![
+            Instruction {
+                dir: Dir::E,
+                steps: 6,
+            },
+            Instruction {
+                dir: Dir::S,
+                steps: 5,
+            },
+            Instruction {
+                dir: Dir::W,
+                steps: 2,
+            },
+            Instruction {
+                dir: Dir::S,
+                steps: 2,
+            },
+            Instruction {
+                dir: Dir::E,
+                steps: 2,
+            },
+            Instruction {
+                dir: Dir::S,
+                steps: 2,
+            },
+            Instruction {
+                dir: Dir::W,
+                steps: 5,
+            },
+            Instruction {
+                dir: Dir::N,
+                steps: 2,
+            },
+            Instruction {
+                dir: Dir::W,
+                steps: 1,
+            },
+            Instruction {
+                dir: Dir::N,
+                steps: 2,
+            },
+            Instruction {
+                dir: Dir::E,
+                steps: 2,
+            },
+            Instruction {
+                dir: Dir::N,
+                steps: 3,
+            },
+            Instruction {
+                dir: Dir::W,
+                steps: 2,
+            },
+            Instruction {
+                dir: Dir::N,
+                steps: 2,
+            },
+        ]
+    }
+
+    #[test]
+    fn parsing() {
+        let input = r"R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
+        assert!(<Solver as crate::Solver>::parse("N 6 (#70c710)").is_err());
+    }
+
+    #[test]
+    fn empty() {
+        for input in ["", "R 0 (#70c710)"] {
+            let input = <Solver as crate::Solver>::parse(input).unwrap();
+
+            assert_eq!(
+                <Solver as crate::Solver>::part_1(&input),
+                Err(Unsolved::NoSolution)
+            );
+        }
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(<Solver as crate::Solver>::part_1(&get_input()), Ok(62));
+    }
+
+    #[test]
+    #[ignore = "not yet implemented"]
+    #[allow(unreachable_code, clippy::diverging_sub_expression)]
+    fn part_2() {
+        assert_eq!(<Solver as crate::Solver>::part_2(&get_input()), todo!());
+    }
+}
@@ -0,0 +1,905 @@
+use std::ops::RangeInclusive;
+
+use crate::graph::{Graph, Interner, NodeId};
+use crate::parse::{self, ParseError};
+use crate::{Answer, Unsolved};
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Op {
+    Gt,
+    Lt,
+}
+
+impl TryFrom<char> for Op {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '>' => Ok(Op::Gt),
+            '<' => Ok(Op::Lt),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Prop {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl TryFrom<char> for Prop {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'x' => Ok(Prop::X),
+            'm' => Ok(Prop::M),
+            'a' => Ok(Prop::A),
+            's' => Ok(Prop::S),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Out {
+    Cont(NodeId),
+    Ignore,
+    Accept,
+    Reject,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Cond {
+    Test {
+        prop: Prop,
+        op: Op,
+        crit: usize,
+        out: Out,
+    },
+    Always(Out),
+}
+
+impl Cond {
+    pub fn apply(&self, part: &Part) -> Out {
+        match self {
+            Cond::Test {
+                prop,
+                op,
+                crit,
+                out,
+            } => {
+                if match op {
+                    Op::Gt => match prop {
+                        Prop::X => part.x > *crit,
+                        Prop::M => part.m > *crit,
+                        Prop::A => part.a > *crit,
+                        Prop::S => part.s > *crit,
+                    },
+                    Op::Lt => match prop {
+                        Prop::X => part.x < *crit,
+                        Prop::M => part.m < *crit,
+                        Prop::A => part.a < *crit,
+                        Prop::S => part.s < *crit,
+                    },
+                } {
+                    out.clone()
+                } else {
+                    Out::Ignore
+                }
+            }
+            Cond::Always(out) => out.clone(),
+        }
+    }
+
+    pub fn split(&self, multipart: &Multipart) -> (Option<(Multipart, Out)>, Option<Multipart>) {
+        match self {
+            Cond::Test {
+                prop,
+                op,
+                crit,
+                out,
+            } => match op {
+                Op::Gt => match prop {
+                    Prop::X => {
+                        let acc =
+                            crit.saturating_add(1).max(*multipart.x.start())..=*multipart.x.end();
+                        let acc = if acc.is_empty() {
+                            None
+                        } else {
+                            Some((
+                                Multipart {
+                                    x: acc,
+                                    ..multipart.clone()
+                                },
+                                out.clone(),
+                            ))
+                        };
+
+                        let rej = *multipart.x.start()..=(*crit).min(*multipart.x.end());
+                        let rej = if rej.is_empty() {
+                            None
+                        } else {
+                            Some(Multipart {
+                                x: rej,
+                                ..multipart.clone()
+                            })
+                        };
+
+                        (acc, rej)
+                    }
+                    Prop::M => {
+                        let acc =
+                            crit.saturating_add(1).max(*multipart.m.start())..=*multipart.m.end();
+                        let acc = if acc.is_empty() {
+                            None
+                        } else {
+                            Some((
+                                Multipart {
+                                    m: acc,
+                                    ..multipart.clone()
+                                },
+                                out.clone(),
+                            ))
+                        };
+
+                        let rej = *multipart.m.start()..=(*crit).min(*multipart.m.end());
+                        let rej = if rej.is_empty() {
+                            None
+                        } else {
+                            Some(Multipart {
+                                m: rej,
+                                ..multipart.clone()
+                            })
+                        };
+
+                        (acc, rej)
+                    }
+                    Prop::A => {
+                        let acc =
+                            crit.saturating_add(1).max(*multipart.a.start())..=*multipart.a.end();
+                        let acc = if acc.is_empty() {
+                            None
+                        } else {
+                            Some((
+                                Multipart {
+                                    a: acc,
+                                    ..multipart.clone()
+                                },
+                                out.clone(),
+                            ))
+                        };
+
+                        let rej = *multipart.a.start()..=(*crit).min(*multipart.a.end());
+                        let rej = if rej.is_empty() {
+                            None
+                        } else {
+                            Some(Multipart {
+                                a: rej,
+                                ..multipart.clone()
+                            })
+                        };
+
+                        (acc, rej)
+                    }
+                    Prop::S => {
+                        let acc =
+                            crit.saturating_add(1).max(*multipart.s.start())..=*multipart.s.end();
+                        let acc = if acc.is_empty() {
+                            None
+                        } else {
+                            Some((
+                                Multipart {
+                                    s: acc,
+                                    ..multipart.clone()
+                                },
+                                out.clone(),
+                            ))
+                        };
+
+                        let rej = *multipart.s.start()..=(*crit).min(*multipart.s.end());
+                        let rej = if rej.is_empty() {
+                            None
+                        } else {
+                            Some(Multipart {
+                                s: rej,
+                                ..multipart.clone()
+                            })
+                        };
+
+                        (acc, rej)
+                    }
+                },
+                Op::Lt => match prop {
+                    Prop::X => {
+                        let acc =
+                            *multipart.x.start()..=crit.saturating_sub(1).min(*multipart.x.end());
+                        let acc = if acc.is_empty() {
+                            None
+                        } else {
+                            Some((
+                                Multipart {
+                                    x: acc,
+                                    ..multipart.clone()
+                                },
+                                out.clone(),
+                            ))
+                        };
+
+                        let rej = (*crit).max(*multipart.x.start())..=*multipart.x.end();
+                        let rej = if rej.is_empty() {
+                            None
+                        } else {
+                            Some(Multipart {
+                                x: rej,
+                                ..multipart.clone()
+                            })
+                        };
+
+                        (acc, rej)
+                    }
+                    Prop::M => {
+                        let acc =
+                            *multipart.m.start()..=crit.saturating_sub(1).min(*multipart.m.end());
+                        let acc = if acc.is_empty() {
+                            None
+                        } else {
+                            Some((
+                                Multipart {
+                                    m: acc,
+                                    ..multipart.clone()
+                                },
+                                out.clone(),
+                            ))
+                        };
+
+                        let rej = (*crit).max(*multipart.m.start())..=*multipart.m.end();
+                        let rej = if rej.is_empty() {
+                            None
+                        } else {
+                            Some(Multipart {
+                                m: rej,
+                                ..multipart.clone()
+                            })
+                        };
+
+                        (acc, rej)
+                    }
+                    Prop::A => {
+                        let acc =
+                            *multipart.a.start()..=crit.saturating_sub(1).min(*multipart.a.end());
+                        let acc = if acc.is_empty() {
+                            None
+                        } else {
+                            Some((
+                                Multipart {
+                                    a: acc,
+                                    ..multipart.clone()
+                                },
+                                out.clone(),
+                            ))
+                        };
+
+                        let rej = (*crit).max(*multipart.a.start())..=*multipart.a.end();
+                        let rej = if rej.is_empty() {
+                            None
+                        } else {
+                            Some(Multipart {
+                                a: rej,
+                                ..multipart.clone()
+                            })
+                        };
+
+                        (acc, rej)
+                    }
+                    Prop::S => {
+                        let acc =
+                            *multipart.s.start()..=crit.saturating_sub(1).min(*multipart.s.end());
+                        let acc = if acc.is_empty() {
+                            None
+                        } else {
+                            Some((
+                                Multipart {
+                                    s: acc,
+                                    ..multipart.clone()
+                                },
+                                out.clone(),
+                            ))
+                        };
+
+                        let rej = (*crit).max(*multipart.s.start())..=*multipart.s.end();
+                        let rej = if rej.is_empty() {
+                            None
+                        } else {
+                            Some(Multipart {
+                                s: rej,
+                                ..multipart.clone()
+                            })
+                        };
+
+                        (acc, rej)
+                    }
+                },
+            },
+            Cond::Always(out) => (Some((multipart.clone(), out.clone())), None),
+        }
+    }
+}
+
+impl Cond {
+    /// Parses a rule such as `a<2006:qkq`, where `parse_out` turns the name of the destination
+    /// into an [`Out`].
+    fn parse<'a>(
+        input: &'a str,
+        mut parse_out: impl FnMut(&'a str) -> Out,
+    ) -> Result<Cond, ParseError> {
+        if let Some(c) = input.chars().nth(1) {
+            if c == '>' || c == '<' {
+                let (test, out) = parse::split_once(input, ":")?;
+                let op_index = test
+                    .find(c)
+                    .ok_or_else(|| ParseError::new(test, format!("expected {c:?}")))?;
+                let (prop, crit) = test.split_at(op_index);
+                let (op, crit) = crit.split_at(1);
+
+                return Ok(Cond::Test {
+                    prop: parse::char(prop)?,
+                    op: parse::char(op)?,
+                    crit: parse::number(crit)?,
+                    out: parse_out(out),
+                });
+            }
+        }
+
+        Ok(Cond::Always(parse_out(input)))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Workflow {
+    conds: Vec<Cond>,
+}
+
+impl Workflow {
+    /// Where the first rule that applies to `part` sends it, or [`Out::Ignore`] if no rule
+    /// applies.
+    pub fn apply(&self, part: &Part) -> Out {
+        self.conds
+            .iter()
+            .map(|cond| cond.apply(part))
+            .find(|out| *out != Out::Ignore)
+            .unwrap_or(Out::Ignore)
+    }
+
+    pub fn apply_multipart(&self, multipart: &Multipart) -> Vec<(Multipart, Out)> {
+        let mut multiparts = vec![];
+        let mut multipart = multipart.clone();
+
+        for cond in &self.conds {
+            let (acc, rej) = cond.split(&multipart);
+
+            if let Some((acc, out)) = acc {
+                multiparts.push((acc, out));
+            }
+
+            if let Some(rej) = rej {
+                multipart = rej;
+            }
+
+            if multipart.is_empty() {
+                break;
+            }
+        }
+
+        multiparts
+    }
+}
+
+/// The workflows, indexed by the [`NodeId`] of their name.
+#[derive(Debug, PartialEq)]
+struct Workflows {
+    workflows: Vec<Workflow>,
+    start: NodeId,
+}
+
+impl Workflows {
+    /// Parses one workflow per line. The last rule of every workflow has to apply to all parts,
+    /// every workflow that is sent to has to be defined exactly once, no part may be sent around
+    /// in a cycle, and there has to be a workflow named `in` to start with.
+    fn parse(section: &str) -> Result<Self, ParseError> {
+        let mut names = Interner::new();
+        let mut workflows = vec![];
+        let mut references = vec![];
+
+        for line in section.lines() {
+            let [name, rules] = parse::pattern(line, "{name}{{{rules}}}")?;
+            let id = names.intern(name);
+
+            let conds = rules
+                .split(',')
+                .map(|rule| {
+                    Cond::parse(rule, |out| match out {
+                        "A" => Out::Accept,
+                        "R" => Out::Reject,
+                        out => {
+                            let to = names.intern(out);
+                            references.push((id, out, to));
+                            Out::Cont(to)
+                        }
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if !matches!(conds.last(), Some(Cond::Always(_))) {
+                let last = rules.rsplit(',').next().unwrap_or(rules);
+                return Err(ParseError::new(
+                    last,
+                    "expected a last rule without a condition",
+                ));
+            }
+
+            workflows.resize(names.len(), None);
+            if workflows[id.index()].replace(Workflow { conds }).is_some() {
+                return Err(ParseError::new(name, "workflow is defined twice"));
+            }
+        }
+
+        workflows.resize(names.len(), None);
+        if let Some((_, name, _)) = references
+            .iter()
+            .find(|(_, _, to)| workflows[to.index()].is_none())
+        {
+            return Err(ParseError::new(name, "workflow is not defined"));
+        }
+
+        // A part that is sent along a cycle would be processed forever. A reference is part of
+        // a cycle if both of its ends are in the same strongly connected component.
+        let mut flow: Graph<(), ()> = std::iter::repeat_n((), names.len()).collect();
+        for &(from, _, to) in &references {
+            flow.add_edge(from, to, ());
+        }
+
+        let mut components = vec![0; names.len()];
+        for (index, component) in flow.strongly_connected_components().iter().enumerate() {
+            for id in component {
+                components[id.index()] = index;
+            }
+        }
+
+        if let Some((_, name, _)) = references
+            .iter()
+            .find(|(from, _, to)| components[from.index()] == components[to.index()])
+        {
+            return Err(ParseError::new(name, "workflows send parts in a cycle"));
+        }
+
+        let start = names
+            .get("in")
+            .ok_or_else(|| ParseError::new(section, "expected a workflow named \"in\""))?;
+
+        // Every workflow is either defined or sent to, so all of them are defined now.
+        Ok(Self {
+            workflows: workflows.into_iter().flatten().collect(),
+            start,
+        })
+    }
+
+    /// Whether `part` is accepted, or `None` if it ends up in a workflow where no rule applies.
+    pub fn accepts(&self, part: &Part) -> Option<bool> {
+        let mut current = self.start;
+
+        loop {
+            match self.workflows[current.index()].apply(part) {
+                Out::Accept => return Some(true),
+                Out::Reject => return Some(false),
+                Out::Cont(next) => current = next,
+                Out::Ignore => return None,
+            }
+        }
+    }
+
+    pub fn get_num_accepted_ratings(&self) -> usize {
+        let mut tentative = vec![(Multipart::new(), Out::Cont(self.start))];
+        let mut accepted = vec![];
+
+        loop {
+            let mut new_tentative = vec![];
+
+            for (multipart, out) in tentative {
+                match out {
+                    Out::Accept => accepted.push(multipart),
+                    Out::Reject | Out::Ignore => continue,
+                    Out::Cont(dst) => {
+                        let mut result = self.workflows[dst.index()].apply_multipart(&multipart);
+                        new_tentative.append(&mut result);
+                    }
+                }
+            }
+
+            if new_tentative.is_empty() {
+                break;
+            }
+
+            tentative = new_tentative;
+        }
+
+        accepted
+            .into_iter()
+            .map(|multipart| multipart.get_num_combinations())
+            .sum()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Part {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+impl Part {
+    fn get_rating(&self) -> usize {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Multipart {
+    x: RangeInclusive<usize>,
+    m: RangeInclusive<usize>,
+    a: RangeInclusive<usize>,
+    s: RangeInclusive<usize>,
+}
+
+impl Multipart {
+    fn new() -> Self {
+        Self {
+            x: 1..=4000,
+            m: 1..=4000,
+            a: 1..=4000,
+            s: 1..=4000,
+        }
+    }
+
+    fn get_num_combinations(&self) -> usize {
+        [&self.x, &self.m, &self.a, &self.s]
+            .iter()
+            .map(|range| range.end() - range.start() + 1)
+            .product()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.x.is_empty() && self.m.is_empty() && self.a.is_empty() && self.s.is_empty()
+    }
+}
+
+impl TryFrom<&str> for Part {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Part, Self::Error> {
+        let [x, m, a, s] = parse::pattern(line, "{{x={x},m={m},a={a},s={s}}}")?;
+
+        Ok(Self {
+            x: parse::number(x)?,
+            m: parse::number(m)?,
+            a: parse::number(a)?,
+            s: parse::number(s)?,
+        })
+    }
+}
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 19;
+
+    type Input = (Workflows, Vec<Part>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut sections = parse::Sections::new(input);
+
+        let workflows = sections.next("workflows", Workflows::parse)?;
+        let parts = sections.next("parts", |section| {
+            section.lines().map(Part::try_from).collect()
+        })?;
+        sections.finish()?;
+
+        Ok((workflows, parts))
+    }
+
+    fn part_1(input: &Self::Input) -> Answer<Self::Output1> {
+        let (workflows, parts) = input;
+
+        let mut sum = 0;
+
+        for part in parts {
+            if workflows.accepts(part).ok_or(Unsolved::NoSolution)? {
+                sum += part.get_rating();
+            }
+        }
+
+        Ok(sum)
+    }
+
+    fn part_2(input: &Self::Input) -> Answer<Self::Output2> {
+        let (workflows, _) = input;
+        Ok(workflows.get_num_accepted_ratings())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> <Solver as crate::Solver>::Input {
+        let mut names = Interner::new();
+        let [px, qkq, rfg, pv, lnx, gd, qs, crn, in_, qqz, hdj] = [
+            "px", "qkq", "rfg", "pv", "lnx", "gd", "qs", "crn", "in", "qqz", "hdj",
+        ]
+        .map(|name| names.intern(name));
+
+        (
+            Workflows {
+                workflows: vec![
+                    // px
+                    Workflow {
+                        conds: vec![
+                            Cond::Test {
+                                prop: Prop::A,
+                                op: Op::Lt,
+                                crit: 2006,
+                                out: Out::Cont(qkq),
+                            },
+                            Cond::Test {
+                                prop: Prop::M,
+                                op: Op::Gt,
+                                crit: 2090,
+                                out: Out::Accept,
+                            },
+                            Cond::Always(Out::Cont(rfg)),
+                        ],
+                    },
+                    // qkq
+                    Workflow {
+                        conds: vec![
+                            Cond::Test {
+                                prop: Prop::X,
+                                op: Op::Lt,
+                                crit: 1416,
+                                out: Out::Accept,
+                            },
+                            Cond::Always(Out::Cont(crn)),
+                        ],
+                    },
+                    // rfg
+                    Workflow {
+                        conds: vec![
+                            Cond::Test {
+                                prop: Prop::S,
+                                op: Op::Lt,
+                                crit: 537,
+                                out: Out::Cont(gd),
+                            },
+                            Cond::Test {
+                                prop: Prop::X,
+                                op: Op::Gt,
+                                crit: 2440,
+                                out: Out::Reject,
+                            },
+                            Cond::Always(Out::Accept),
+                        ],
+                    },
+                    // pv
+                    Workflow {
+                        conds: vec![
+                            Cond::Test {
+                                prop: Prop::A,
+                                op: Op::Gt,
+                                crit: 1716,
+                                out: Out::Reject,
+                            },
+                            Cond::Always(Out::Accept),
+                        ],
+                    },
+                    // lnx
+                    Workflow {
+                        conds: vec![
+                            Cond::Test {
+                                prop: Prop::M,
+                                op: Op::Gt,
+                                crit: 1548,
+                                out: Out::Accept,
+                            },
+                            Cond::Always(Out::Accept),
+                        ],
+                    },
+                    // gd
+                    Workflow {
+                        conds: vec![
+                            Cond::Test {
+                                prop: Prop::A,
+                                op: Op::Gt,
+                                crit: 3333,
+                                out: Out::Reject,
+                            },
+                            Cond::Always(Out::Reject),
+                        ],
+                    },
+                    // qs
+                    Workflow {
+                        conds: vec![
+                            Cond::Test {
+                                prop: Prop::S,
+                                op: Op::Gt,
+                                crit: 3448,
+                                out: Out::Accept,
+                            },
+                            Cond::Always(Out::Cont(lnx)),
+                        ],
+                    },
+                    // crn
+                    Workflow {
+                        conds: vec![
+                            Cond::Test {
+                                prop: Prop::X,
+                                op: Op::Gt,
+                                crit: 2662,
+                                out: Out::Accept,
+                            },
+                            Cond::Always(Out::Reject),
+                        ],
+                    },
+                    // in
+                    Workflow {
+                        conds: vec![
+                            Cond::Test {
+                                prop: Prop::S,
+                                op: Op::Lt,
+                                crit: 1351,
+                                out: Out::Cont(px),
+                            },
+                            Cond::Always(Out::Cont(qqz)),
+                        ],
+                    },
+                    // qqz
+                    Workflow {
+                        conds: vec![
+                            Cond::Test {
+                                prop: Prop::S,
+                                op: Op::Gt,
+                                crit: 2770,
+                                out: Out::Cont(qs),
+                            },
+                            Cond::Test {
+                                prop: Prop::M,
+                                op: Op::Lt,
+                                crit: 1801,
+                                out: Out::Cont(hdj),
+                            },
+                            Cond::Always(Out::Reject),
+                        ],
+                    },
+                    // hdj
+                    Workflow {
+                        conds: vec![
+                            Cond::Test {
+                                prop: Prop::M,
+                                op: Op::Gt,
+                                crit: 838,
+                                out: Out::Accept,
+                            },
+                            Cond::Always(Out::Cont(pv)),
+                        ],
+                    },
+                ],
+                start: in_,
+            },
+            vec![
+                Part {
+                    x: 787,
+                    m: 2655,
+                    a: 1222,
+                    s: 2876,
+                },
+                Part {
+                    x: 1679,
+                    m: 44,
+                    a: 2067,
+                    s: 496,
+                },
+                Part {
+                    x: 2036,
+                    m: 264,
+                    a: 79,
+                    s: 2244,
+                },
+                Part {
+                    x: 2461,
+                    m: 1339,
+                    a: 466,
+                    s: 291,
+                },
+                Part {
+                    x: 2127,
+                    m: 1623,
+                    a: 2188,
+                    s: 1013,
+                },
+            ],
+        )
+    }
+
+    #[test]
+    fn parsing() {
+        let input = r"px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
+        assert!(<Solver as crate::Solver>::parse("in{a<1:A,px}\n\n{x=1,m=2,a=3,s=4}").is_err());
+        assert!(<Solver as crate::Solver>::parse("px{a<1:A,R}\n\n{x=1,m=2,a=3,s=4}").is_err());
+    }
+
+    #[test]
+    fn fallback() {
+        let input = "in{x>5:A}\n\n{x=1,m=2,a=3,s=4}";
+        let error = <Solver as crate::Solver>::parse(input)
+            .unwrap_err()
+            .locate(input);
+        assert_eq!((error.line(), error.column()), (Some(1), Some(4)));
+
+        let input = "in{x<1:R,A}\n\n{x=1,m=2,a=3,s=4}";
+        let input = <Solver as crate::Solver>::parse(input).unwrap();
+        assert_eq!(<Solver as crate::Solver>::part_1(&input), Ok(10));
+        assert_eq!(
+            <Solver as crate::Solver>::part_2(&input),
+            Ok(4000 * 4000 * 4000 * 4000)
+        );
+    }
+
+    #[test]
+    fn cycle() {
+        let input = "in{a<1:A,px}\npx{m>5:in,R}\n\n{x=1,m=2,a=3,s=4}";
+        let error = <Solver as crate::Solver>::parse(input)
+            .unwrap_err()
+            .locate(input);
+        assert_eq!((error.line(), error.column()), (Some(1), Some(10)));
+
+        assert!(<Solver as crate::Solver>::parse("in{a<1:in,A}\n\n{x=1,m=2,a=3,s=4}").is_err());
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(<Solver as crate::Solver>::part_1(&get_input()), Ok(19114));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(
+            <Solver as crate::Solver>::part_2(&get_input()),
+            Ok(167409079868000)
+        );
+    }
+}
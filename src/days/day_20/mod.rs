@@ -0,0 +1,227 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::graph::NodeId;
+use crate::{Answer, ParseError, Unsolved};
+
+mod parsing;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PulseType {
+    Low,
+    High,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Pulse {
+    src: NodeId,
+    typ: PulseType,
+    dst: NodeId,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Module {
+    Broadcaster {
+        outputs: Vec<NodeId>,
+    },
+    FlipFlop {
+        is_on: bool,
+        outputs: Vec<NodeId>,
+    },
+    Conj {
+        inputs: HashMap<NodeId, PulseType>,
+        outputs: Vec<NodeId>,
+    },
+    Sink,
+}
+
+impl Module {
+    /// Handles `pulse`, which was sent to this module, and returns the pulses it sends in turn.
+    fn handle(&mut self, pulse: &Pulse) -> Vec<Pulse> {
+        let name = pulse.dst;
+
+        match self {
+            Module::Broadcaster { outputs } => handle_broadcaster(name, outputs, pulse),
+            Module::FlipFlop { is_on, outputs } => handle_flipflop(name, is_on, outputs, pulse),
+            Module::Conj { inputs, outputs } => handle_conj(name, inputs, outputs, pulse),
+            Module::Sink => vec![],
+        }
+    }
+
+    fn get_outputs(&self) -> &[NodeId] {
+        match self {
+            Module::Broadcaster { outputs } => outputs,
+            Module::FlipFlop { outputs, .. } => outputs,
+            Module::Conj { outputs, .. } => outputs,
+            Module::Sink => &[],
+        }
+    }
+}
+
+fn handle_broadcaster(name: NodeId, outputs: &[NodeId], pulse: &Pulse) -> Vec<Pulse> {
+    outputs
+        .iter()
+        .map(|&output| Pulse {
+            typ: pulse.typ,
+            src: name,
+            dst: output,
+        })
+        .collect()
+}
+
+fn handle_flipflop(
+    name: NodeId,
+    is_on: &mut bool,
+    outputs: &[NodeId],
+    pulse: &Pulse,
+) -> Vec<Pulse> {
+    if pulse.typ == PulseType::High {
+        vec![]
+    } else {
+        *is_on = !*is_on;
+        outputs
+            .iter()
+            .map(|&output| Pulse {
+                typ: if *is_on {
+                    PulseType::High
+                } else {
+                    PulseType::Low
+                },
+                src: name,
+                dst: output,
+            })
+            .collect()
+    }
+}
+
+fn handle_conj(
+    name: NodeId,
+    inputs: &mut HashMap<NodeId, PulseType>,
+    outputs: &[NodeId],
+    pulse: &Pulse,
+) -> Vec<Pulse> {
+    *inputs.get_mut(&pulse.src).unwrap() = pulse.typ;
+
+    outputs
+        .iter()
+        .map(|&output| Pulse {
+            typ: if inputs.values().all(|val| *val == PulseType::High) {
+                PulseType::Low
+            } else {
+                PulseType::High
+            },
+            src: name,
+            dst: output,
+        })
+        .collect()
+}
+
+/// The modules, indexed by the [`NodeId`] of their name.
+#[derive(Clone, Debug, PartialEq)]
+struct Conf {
+    modules: Vec<Module>,
+    broadcaster: NodeId,
+}
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 20;
+
+    type Input = Conf;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parsing::parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer<Self::Output1> {
+        let mut config = input.clone();
+
+        let mut low_pulses = 0;
+        let mut high_pulses = 0;
+
+        for _ in 0..1000 {
+            // The button is not a module, but only conjunctions look at where a pulse came from.
+            let mut pulses = VecDeque::from([Pulse {
+                src: config.broadcaster,
+                typ: PulseType::Low,
+                dst: config.broadcaster,
+            }]);
+
+            while let Some(pulse) = pulses.pop_front() {
+                match pulse.typ {
+                    PulseType::Low => low_pulses += 1,
+                    PulseType::High => high_pulses += 1,
+                }
+
+                pulses.extend(config.modules[pulse.dst.index()].handle(&pulse));
+            }
+        }
+
+        Ok(low_pulses * high_pulses)
+    }
+
+    fn part_2(_input: &Self::Input) -> Answer<Self::Output2> {
+        Err(Unsolved::NotImplemented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Interner;
+
+    fn get_input() -> <Solver as crate::Solver>::Input {
+        let mut names = Interner::new();
+        let [broadcaster, a, inv, con, b, output] =
+            ["broadcaster", "a", "inv", "con", "b", "output"].map(|name| names.intern(name));
+
+        Conf {
+            modules: vec![
+                Module::Broadcaster { outputs: vec![a] },
+                Module::FlipFlop {
+                    is_on: false,
+                    outputs: vec![inv, con],
+                },
+                Module::Conj {
+                    inputs: [(a, PulseType::Low)].into(),
+                    outputs: vec![b],
+                },
+                Module::Conj {
+                    inputs: [(a, PulseType::Low), (b, PulseType::Low)].into(),
+                    outputs: vec![output],
+                },
+                Module::FlipFlop {
+                    is_on: false,
+                    outputs: vec![con],
+                },
+                Module::Sink,
+            ],
+            broadcaster,
+        }
+    }
+
+    #[test]
+    fn parsing() {
+        let input = r"broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
+        assert!(<Solver as crate::Solver>::parse("%a -> b\n&b -> a").is_err());
+        assert!(<Solver as crate::Solver>::parse("broadcaster -> a\n%a -> a\n&a -> a").is_err());
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(
+            <Solver as crate::Solver>::part_1(&get_input()),
+            Ok(11687500)
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use super::{Conf, Module, PulseType};
+use crate::graph::Interner;
+use crate::parse::{self, ParseError};
+
+/// Parses one module per line. Modules that are sent to but not defined are sinks.
+pub(crate) fn parse(input: &str) -> Result<Conf, ParseError> {
+    let mut names = Interner::new();
+    let mut modules = vec![];
+
+    for line in input.lines() {
+        let (name, module) = parse_line(line, &mut names)?;
+        let id = names.intern(name);
+
+        modules.resize(names.len(), None);
+        if modules[id.index()].replace(module).is_some() {
+            return Err(ParseError::new(name, "module is defined twice"));
+        }
+    }
+
+    modules.resize(names.len(), None);
+    let mut modules: Vec<_> = modules
+        .into_iter()
+        .map(|module| module.unwrap_or(Module::Sink))
+        .collect();
+
+    for (id, _) in names.iter() {
+        for output in modules[id.index()].get_outputs().to_vec() {
+            if let Module::Conj { inputs, .. } = &mut modules[output.index()] {
+                inputs.insert(id, PulseType::Low);
+            }
+        }
+    }
+
+    let broadcaster = names
+        .get("broadcaster")
+        .ok_or_else(|| ParseError::new(input, "expected a broadcaster"))?;
+
+    Ok(Conf {
+        modules,
+        broadcaster,
+    })
+}
+
+fn parse_line<'a>(line: &'a str, names: &mut Interner) -> Result<(&'a str, Module), ParseError> {
+    let (name, outputs) = parse::split_once(line, " -> ")?;
+
+    let (kind, name) = match name.strip_prefix(['%', '&']) {
+        Some(rest) => name.split_at(name.len() - rest.len()),
+        None => ("", name),
+    };
+
+    // Intern the name before the outputs, so that modules are numbered in order of appearance.
+    names.intern(name);
+
+    let outputs = outputs
+        .split(", ")
+        .map(|output| names.intern(output))
+        .collect();
+
+    let module = match kind {
+        "%" => Module::FlipFlop {
+            is_on: false,
+            outputs,
+        },
+        "&" => Module::Conj {
+            inputs: HashMap::new(),
+            outputs,
+        },
+        _ => Module::Broadcaster { outputs },
+    };
+
+    Ok((name, module))
+}
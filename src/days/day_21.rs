@@ -0,0 +1,277 @@
+use crate::grid::Pos;
+use crate::parse::ParseError;
+use crate::{search, Answer, Unsolved};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Tile {
+    Start,
+    Garden,
+    Rock,
+}
+
+impl Tile {
+    pub fn new_start() -> Self {
+        Self::Start
+    }
+    pub fn new_garden() -> Self {
+        Self::Garden
+    }
+    pub fn new_rock() -> Self {
+        Self::Rock
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'S' => Ok(Self::new_start()),
+            '.' => Ok(Self::new_garden()),
+            '#' => Ok(Self::new_rock()),
+            _ => Err(()),
+        }
+    }
+}
+
+type Grid = crate::Grid<Tile>;
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 21;
+
+    type Input = Grid;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer<Self::Output1> {
+        get_num_reachable_tiles(input, 64)
+    }
+
+    fn part_2(_input: &Self::Input) -> Answer<Self::Output2> {
+        Err(Unsolved::NotImplemented)
+    }
+}
+
+fn get_num_reachable_tiles(grid: &Grid, num_steps: usize) -> Answer<usize> {
+    let start = find_start(grid).ok_or(Unsolved::NoSolution)?;
+    let map = search::distance_map(grid, start, |_, to| *grid.get(to.x, to.y) != Tile::Rock);
+
+    // A tile that can be reached in fewer steps can be reached again by stepping back and
+    // forth, as long as the number of remaining steps is even.
+    Ok(map
+        .distances()
+        .iter()
+        .flatten()
+        .filter(|&&distance| distance <= num_steps && distance % 2 == num_steps % 2)
+        .count())
+}
+
+fn find_start(grid: &Grid) -> Option<Pos> {
+    grid.find(|tile| *tile == Tile::Start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> <Solver as crate::Solver>::Input {
+        Grid::from_iter([
+            [
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+            ]
+            .into_iter(),
+            [
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_rock(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+            ]
+            .into_iter(),
+            [
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_rock(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+            ]
+            .into_iter(),
+            [
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+            ]
+            .into_iter(),
+            [
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+            ]
+            .into_iter(),
+            [
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_start(),
+                Tile::new_rock(),
+                Tile::new_rock(),
+                Tile::new_rock(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+            ]
+            .into_iter(),
+            [
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+            ]
+            .into_iter(),
+            [
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+            ]
+            .into_iter(),
+            [
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_rock(),
+                Tile::new_rock(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+            ]
+            .into_iter(),
+            [
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+                Tile::new_rock(),
+                Tile::new_rock(),
+                Tile::new_garden(),
+            ]
+            .into_iter(),
+            [
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+                Tile::new_garden(),
+            ]
+            .into_iter(),
+        ])
+    }
+
+    #[test]
+    fn parsing() {
+        let input = r"...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+";
+
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(get_num_reachable_tiles(&get_input(), 6), Ok(16));
+        assert_eq!(
+            get_num_reachable_tiles(&Grid::from_iter([[Tile::new_garden()]]), 6),
+            Err(Unsolved::NoSolution)
+        );
+    }
+
+    #[test]
+    #[ignore = "not yet implemented"]
+    #[allow(unreachable_code, clippy::diverging_sub_expression)]
+    fn part_2() {
+        assert_eq!(<Solver as crate::Solver>::part_2(&get_input()), todo!());
+    }
+}
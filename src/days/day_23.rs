@@ -0,0 +1,769 @@
+use std::collections::HashSet;
+
+use crate::grid::Pos;
+use crate::parse::ParseError;
+use crate::{Answer, Dir, Unsolved};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Cell {
+    Path,
+    Forest,
+    Slope(Dir),
+}
+
+impl TryFrom<char> for Cell {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Self::Path),
+            '#' => Ok(Self::Forest),
+            c => Dir::from_arrow(c).map(Self::Slope).ok_or(()),
+        }
+    }
+}
+
+fn get_num_paths(grid: &Grid, cur: Pos, end: Pos, mut visited: HashSet<Pos>) -> usize {
+    if cur == end {
+        visited.len() - 1 // Do not count Start
+    } else {
+        match *grid.get(cur.x, cur.y) {
+            Cell::Path => {
+                let mut neighbors = grid.get_neighbors(&cur);
+                neighbors.retain(|pos| {
+                    !visited.contains(pos) && *grid.get(pos.x, pos.y) != Cell::Forest
+                });
+
+                if neighbors.is_empty() {
+                    0
+                } else if neighbors.len() == 1 {
+                    visited.insert(neighbors[0]);
+                    get_num_paths(grid, neighbors[0], end, visited)
+                } else {
+                    let max: usize = neighbors
+                        .iter()
+                        .skip(1)
+                        .map(|&next| {
+                            let mut visited = visited.clone();
+                            visited.insert(next);
+                            get_num_paths(grid, next, end, visited)
+                        })
+                        .max()
+                        .unwrap();
+                    visited.insert(neighbors[0]);
+                    max.max(get_num_paths(grid, neighbors[0], end, visited))
+                }
+            }
+            Cell::Forest => 0,
+            Cell::Slope(dir) => match cur.checked_apply(dir, grid) {
+                Some(next) if visited.insert(next) => get_num_paths(grid, next, end, visited),
+                _ => 0,
+            },
+        }
+    }
+}
+
+type Grid = crate::Grid<Cell>;
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 23;
+
+    type Input = Grid;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_1(grid: &Self::Input) -> Answer<Self::Output1> {
+        // The start is in the second column of the first row, and the first step goes down.
+        if grid.width() < 2 || grid.height() < 2 {
+            return Err(Unsolved::NoSolution);
+        }
+
+        let start = Pos { x: 1, y: 0 };
+        let next = Pos { x: 1, y: 1 };
+        let end = Pos {
+            x: grid.width() - 2,
+            y: grid.height() - 1,
+        };
+        let visited = HashSet::from_iter([start, next]);
+
+        Ok(get_num_paths(grid, next, end, visited))
+    }
+
+    fn part_2(_input: &Self::Input) -> Answer<Self::Output2> {
+        Err(Unsolved::NotImplemented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> <Solver as crate::Solver>::Input {
+        Grid::from_iter([
+            [
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Slope(Dir::E),
+                Cell::Path,
+                Cell::Slope(Dir::E),
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Slope(Dir::S),
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Slope(Dir::S),
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Slope(Dir::E),
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Slope(Dir::S),
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Slope(Dir::S),
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Slope(Dir::E),
+                Cell::Path,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Slope(Dir::S),
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Slope(Dir::S),
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Slope(Dir::S),
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Slope(Dir::E),
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Slope(Dir::E),
+                Cell::Path,
+                Cell::Slope(Dir::E),
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Slope(Dir::S),
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Slope(Dir::S),
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Slope(Dir::S),
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Slope(Dir::S),
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Slope(Dir::E),
+                Cell::Path,
+                Cell::Slope(Dir::E),
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Slope(Dir::E),
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Slope(Dir::S),
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Path,
+                Cell::Path,
+                Cell::Forest,
+            ]
+            .into_iter(),
+            [
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Forest,
+                Cell::Path,
+                Cell::Forest,
+            ]
+            .into_iter(),
+        ])
+    }
+
+    #[test]
+    fn parsing() {
+        let input = r"#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
+        assert!(<Solver as crate::Solver>::parse("#.#\n#U#\n#.#").is_err());
+    }
+
+    #[test]
+    fn too_small() {
+        for input in ["", ".", "#.#", "#\n.\n#"] {
+            let input = <Solver as crate::Solver>::parse(input).unwrap();
+
+            assert_eq!(
+                <Solver as crate::Solver>::part_1(&input),
+                Err(Unsolved::NoSolution)
+            );
+        }
+
+        // A slope that points out of the grid leads nowhere.
+        let input = <Solver as crate::Solver>::parse("#.#\n<.#\n#.#").unwrap();
+        assert_eq!(<Solver as crate::Solver>::part_1(&input), Ok(2));
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(<Solver as crate::Solver>::part_1(&get_input()), Ok(94));
+    }
+
+    #[test]
+    #[ignore = "not yet implemented"]
+    #[allow(unreachable_code, clippy::diverging_sub_expression)]
+    fn part_2() {
+        assert_eq!(<Solver as crate::Solver>::part_2(&get_input()), todo!());
+    }
+}
@@ -0,0 +1,243 @@
+use crate::parse::{self, ParseError};
+use crate::{Answer, Unsolved};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Coord {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+impl TryFrom<&str> for Coord {
+    type Error = ParseError;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        let mut splits = string.split(',').map(str::trim);
+
+        Ok(Self {
+            x: parse::number(parse::next(&mut splits, string, "an x coordinate")?)?,
+            y: parse::number(parse::next(&mut splits, string, "a y coordinate")?)?,
+            z: parse::number(parse::next(&mut splits, string, "a z coordinate")?)?,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Hailstone {
+    pos: Coord,
+    vel: Coord,
+}
+
+impl Hailstone {
+    fn intersects(&self, other: &Hailstone) -> Option<Coord> {
+        let x1 = self.pos.x;
+        let x2 = self.pos.y;
+        let x3 = other.pos.x;
+        let x4 = other.pos.y;
+
+        let v1 = self.vel.x;
+        let v2 = self.vel.y;
+        let v3 = other.vel.x;
+        let v4 = other.vel.y;
+
+        if v1 == 0.0 || (v4 - v2 * v3 / v1) == 0.0 {
+            None
+        } else {
+            let mu = (x2 - x4 + v2 * (x3 - x1) / v1) / (v4 - v2 * v3 / v1);
+            let lambda = (x3 - x1 + mu * v3) / v1;
+
+            if mu < 0.0 || lambda < 0.0 {
+                None
+            } else {
+                Some(Coord {
+                    x: x1 + lambda * v1,
+                    y: x2 + lambda * v2,
+                    z: 0.0,
+                })
+            }
+        }
+    }
+
+    fn intersects_in_area(&self, other: &Hailstone, min: f32, max: f32) -> bool {
+        self.intersects(other)
+            .map(|coord| min <= coord.x && coord.x <= max && min <= coord.y && coord.y <= max)
+            .unwrap_or(false)
+    }
+}
+
+impl TryFrom<&str> for Hailstone {
+    type Error = ParseError;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        let (pos, vel) = parse::split_once(string, "@")?;
+
+        Ok(Self {
+            pos: pos.try_into()?,
+            vel: vel.try_into()?,
+        })
+    }
+}
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 24;
+
+    type Input = Vec<Hailstone>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(Hailstone::try_from).collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Answer<Self::Output1> {
+        Ok(input
+            .iter()
+            .enumerate()
+            .map(|(i, a)| {
+                input
+                    .iter()
+                    .skip(i + 1)
+                    .filter(|b| a.intersects_in_area(b, 200000000000000.0, 400000000000000.0))
+                    .count()
+            })
+            .sum())
+    }
+
+    fn part_2(_input: &Self::Input) -> Answer<Self::Output2> {
+        Err(Unsolved::NotImplemented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> <Solver as crate::Solver>::Input {
+        vec![
+            Hailstone {
+                pos: Coord {
+                    x: 19.0,
+                    y: 13.0,
+                    z: 30.0,
+                },
+                vel: Coord {
+                    x: -2.0,
+                    y: 1.0,
+                    z: -2.0,
+                },
+            },
+            Hailstone {
+                pos: Coord {
+                    x: 18.0,
+                    y: 19.0,
+                    z: 22.0,
+                },
+                vel: Coord {
+                    x: -1.0,
+                    y: -1.0,
+                    z: -2.0,
+                },
+            },
+            Hailstone {
+                pos: Coord {
+                    x: 20.0,
+                    y: 25.0,
+                    z: 34.0,
+                },
+                vel: Coord {
+                    x: -2.0,
+                    y: -2.0,
+                    z: -4.0,
+                },
+            },
+            Hailstone {
+                pos: Coord {
+                    x: 12.0,
+                    y: 31.0,
+                    z: 28.0,
+                },
+                vel: Coord {
+                    x: -1.0,
+                    y: -2.0,
+                    z: -1.0,
+                },
+            },
+            Hailstone {
+                pos: Coord {
+                    x: 20.0,
+                    y: 19.0,
+                    z: 15.0,
+                },
+                vel: Coord {
+                    x: 1.0,
+                    y: -5.0,
+                    z: -3.0,
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn parsing() {
+        let input = r"19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
+    }
+
+    #[test]
+    fn part_1() {
+        let a = Hailstone::try_from("19, 13, 30 @ -2, 1, -2").unwrap();
+        let b = Hailstone::try_from("18, 19, 22 @ -1, -1, -2").unwrap();
+        assert!(a.intersects(&b).is_some());
+
+        let a = Hailstone::try_from("19, 13, 30 @ -2, 1, -2").unwrap();
+        let b = Hailstone::try_from("20, 25, 34 @ -2, -2, -4").unwrap();
+        assert!(a.intersects(&b).is_some());
+
+        let a = Hailstone::try_from("19, 13, 30 @ -2, 1, -2").unwrap();
+        let b = Hailstone::try_from("12, 31, 28 @ -1, -2, -1").unwrap();
+        assert!(a.intersects(&b).is_some());
+
+        let a = Hailstone::try_from("19, 13, 30 @ -2, 1, -2").unwrap();
+        let b = Hailstone::try_from("20, 19, 15 @ 1, -5, -3").unwrap();
+        assert!(a.intersects(&b).is_none());
+
+        let a = Hailstone::try_from("18, 19, 22 @ -1, -1, -2").unwrap();
+        let b = Hailstone::try_from("20, 25, 34 @ -2, -2, -4").unwrap();
+        assert!(a.intersects(&b).is_none());
+
+        let a = Hailstone::try_from("18, 19, 22 @ -1, -1, -2").unwrap();
+        let b = Hailstone::try_from("12, 31, 28 @ -1, -2, -1").unwrap();
+        assert!(a.intersects(&b).is_some());
+
+        let a = Hailstone::try_from("18, 19, 22 @ -1, -1, -2").unwrap();
+        let b = Hailstone::try_from("20, 19, 15 @ 1, -5, -3").unwrap();
+        assert!(a.intersects(&b).is_none());
+
+        let a = Hailstone::try_from("20, 25, 34 @ -2, -2, -4").unwrap();
+        let b = Hailstone::try_from("12, 31, 28 @ -1, -2, -1").unwrap();
+        assert!(a.intersects(&b).is_some());
+
+        let a = Hailstone::try_from("20, 25, 34 @ -2, -2, -4").unwrap();
+        let b = Hailstone::try_from("20, 19, 15 @ 1, -5, -3").unwrap();
+        assert!(a.intersects(&b).is_none());
+
+        let a = Hailstone::try_from("12, 31, 28 @ -1, -2, -1").unwrap();
+        let b = Hailstone::try_from("20, 19, 15 @ 1, -5, -3").unwrap();
+        assert!(a.intersects(&b).is_none());
+    }
+
+    #[test]
+    #[ignore = "not yet implemented"]
+    fn part_2() {
+        todo!()
+    }
+}
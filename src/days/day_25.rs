@@ -0,0 +1,142 @@
+use crate::graph::{self, Interner};
+use crate::parse::{self, ParseError};
+use crate::{Answer, Unsolved};
+
+/// The components, numbered in order of appearance, and the wires between them.
+#[derive(Debug, PartialEq)]
+struct Wiring {
+    num_components: usize,
+    wires: Vec<(usize, usize)>,
+}
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 25;
+
+    type Input = Wiring;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut names = Interner::new();
+        let mut wires = vec![];
+
+        for line in input.lines() {
+            let (component, connected) = parse::split_once(line, ": ")?;
+
+            let component = names.intern(component).index();
+            for other in connected.split_whitespace() {
+                wires.push((component, names.intern(other).index()));
+            }
+        }
+
+        Ok(Wiring {
+            num_components: names.len(),
+            wires,
+        })
+    }
+
+    fn part_1(wiring: &Self::Input) -> Answer<Self::Output1> {
+        let wires: Vec<_> = wiring.wires.iter().map(|&(a, b)| (a, b, 1)).collect();
+
+        match graph::min_cut(wiring.num_components, &wires) {
+            Some(cut) if cut.weight == 3 => {
+                Ok(cut.nodes.len() * (wiring.num_components - cut.nodes.len()))
+            }
+            _ => Err(Unsolved::NoSolution),
+        }
+    }
+
+    fn part_2(_input: &Self::Input) -> Answer<Self::Output2> {
+        Err(Unsolved::NoPuzzle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> <Solver as crate::Solver>::Input {
+        Wiring {
+            num_components: 15,
+            wires: vec![
+                (0, 1),
+                (0, 2),
+                (0, 3),
+                (4, 5),
+                (4, 6),
+                (4, 7),
+                (2, 8),
+                (9, 10),
+                (9, 3),
+                (9, 11),
+                (9, 12),
+                (1, 2),
+                (1, 12),
+                (1, 8),
+                (12, 2),
+                (12, 8),
+                (6, 7),
+                (6, 8),
+                (6, 3),
+                (10, 3),
+                (13, 0),
+                (13, 8),
+                (13, 12),
+                (13, 2),
+                (3, 11),
+                (7, 11),
+                (14, 10),
+                (14, 9),
+                (14, 7),
+                (14, 4),
+                (5, 10),
+                (5, 11),
+                (5, 7),
+            ],
+        }
+    }
+
+    #[test]
+    fn parsing() {
+        let input = "\
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
+        assert_eq!(
+            <Solver as crate::Solver>::parse("a: b c\nc: a"),
+            Ok(Wiring {
+                num_components: 3,
+                wires: vec![(0, 1), (0, 2), (2, 0)],
+            })
+        );
+        assert!(<Solver as crate::Solver>::parse("a b c").is_err());
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(<Solver as crate::Solver>::part_1(&get_input()), Ok(54));
+    }
+    #[test]
+    fn part_2() {
+        assert_eq!(
+            <Solver as crate::Solver>::part_2(&get_input()),
+            Err(Unsolved::NoPuzzle)
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use crate::parse::{self, ParseError};
+use crate::Answer;
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+#[derive(Debug, PartialEq)]
+struct Card {
+    winning_numbers: Vec<u32>,
+    present_numbers: Vec<u32>,
+}
+
+impl Card {
+    fn get_score(&self) -> u32 {
+        let num_matches = self.get_num_matches();
+
+        if num_matches >= 1 {
+            2u32.pow(num_matches as u32 - 1)
+        } else {
+            0
+        }
+    }
+
+    fn get_num_matches(&self) -> usize {
+        self.present_numbers
+            .iter()
+            .filter(|present_number| {
+                self.winning_numbers
+                    .iter()
+                    .any(|winning_number| winning_number == *present_number)
+            })
+            .count()
+    }
+}
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+    type Output1 = u32;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(parse_line).collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Answer<Self::Output1> {
+        Ok(input.iter().map(|card| card.get_score()).sum())
+    }
+
+    fn part_2(input: &Self::Input) -> Answer<Self::Output2> {
+        let mut cards: Vec<(&Card, usize)> = input.iter().map(|card| (card, 1)).collect();
+
+        for i in 0..cards.len() {
+            let card = cards[i].0;
+            let num_matches = card.get_num_matches();
+
+            for j in 0..num_matches {
+                cards[i + j + 1].1 += cards[i].1;
+            }
+        }
+
+        Ok(cards.iter().map(|(_, num)| num).sum())
+    }
+}
+
+fn parse_line(line: &str) -> Result<Card, ParseError> {
+    let (_, line) = parse::split_once(line, ":")?;
+    let (winning_string, present_string) = parse::split_once(line, " | ")?;
+
+    Ok(Card {
+        winning_numbers: parse::numbers(winning_string)?,
+        present_numbers: parse::numbers(present_string)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> Vec<Card> {
+        vec![
+            Card {
+                winning_numbers: vec![41, 48, 83, 86, 17],
+                present_numbers: vec![83, 86, 6, 31, 17, 9, 48, 53],
+            },
+            Card {
+                winning_numbers: vec![13, 32, 20, 16, 61],
+                present_numbers: vec![61, 30, 68, 82, 17, 32, 24, 19],
+            },
+            Card {
+                winning_numbers: vec![1, 21, 53, 59, 44],
+                present_numbers: vec![69, 82, 63, 72, 16, 21, 14, 1],
+            },
+            Card {
+                winning_numbers: vec![41, 92, 73, 84, 69],
+                present_numbers: vec![59, 84, 76, 51, 58, 5, 54, 83],
+            },
+            Card {
+                winning_numbers: vec![87, 83, 26, 28, 32],
+                present_numbers: vec![88, 30, 70, 12, 93, 22, 82, 36],
+            },
+            Card {
+                winning_numbers: vec![31, 18, 13, 56, 72],
+                present_numbers: vec![74, 77, 10, 23, 35, 67, 36, 11],
+            },
+        ]
+    }
+
+    #[test]
+    fn parsing() {
+        let input = r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
+        assert!(<Solver as crate::Solver>::parse("Card 1: 41 4x8 | 83").is_err());
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(<Solver as crate::Solver>::part_1(&get_input()), Ok(13));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(<Solver as crate::Solver>::part_2(&get_input()), Ok(30));
+    }
+}
@@ -0,0 +1,229 @@
+use crate::parse::{self, ParseError};
+use crate::{Answer, Unsolved};
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialOrd, PartialEq)]
+struct Range {
+    start: u64,
+    dst: u64,
+    len: u64,
+}
+
+#[derive(Debug, PartialEq)]
+struct Map {
+    ranges: Vec<Range>,
+}
+
+impl Map {
+    fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    fn add(&mut self, range: Range) {
+        self.ranges.push(range);
+        self.ranges.sort_unstable_by_key(|range| range.start);
+    }
+
+    fn transform(&self, seed: u64) -> u64 {
+        match self.ranges.binary_search_by_key(&seed, |range| range.start) {
+            Ok(index) if self.ranges[index].len >= 1 => self.ranges[index].dst,
+            Ok(_) => seed,
+            Err(0) => seed,
+            Err(index) => {
+                let range = &self.ranges[index - 1];
+
+                if seed < range.start + range.len {
+                    range.dst + seed - range.start
+                } else {
+                    seed
+                }
+            }
+        }
+    }
+}
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 5;
+
+    type Input = (Vec<u64>, [Map; 7]);
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut sections = parse::Sections::new(input);
+
+        let seeds = sections.next("seeds", parse_seeds)?;
+        let maps = sections.array("a map", parse_map)?;
+        sections.finish()?;
+
+        Ok((seeds, maps))
+    }
+
+    fn part_1(input: &Self::Input) -> Answer<Self::Output1> {
+        let (seeds, steps) = input;
+
+        seeds
+            .iter()
+            .map(|seed| {
+                steps
+                    .iter()
+                    .fold(*seed, |transformed, map| map.transform(transformed))
+            })
+            .min()
+            .ok_or(Unsolved::NoSolution)
+    }
+
+    fn part_2(input: &Self::Input) -> Answer<Self::Output2> {
+        let (seeds, steps) = input;
+
+        seeds
+            .chunks_exact(2)
+            .filter_map(|chunk| {
+                let (start, len) = (chunk[0], chunk[1]);
+                (start..start + len)
+                    .map(|seed| {
+                        steps
+                            .iter()
+                            .fold(seed, |transformed, map| map.transform(transformed))
+                    })
+                    .min()
+            })
+            .min()
+            .ok_or(Unsolved::NoSolution)
+    }
+}
+
+fn parse_seeds(section: &str) -> Result<Vec<u64>, ParseError> {
+    let section = parse::strip_prefix(section, "seeds: ")?;
+
+    section.split_whitespace().map(parse::number).collect()
+}
+
+fn parse_map(section: &str) -> Result<Map, ParseError> {
+    let mut map = Map::new();
+
+    for range in section.lines().skip(1).map(parse_map_line) {
+        map.add(range?);
+    }
+
+    Ok(map)
+}
+
+fn parse_map_line(line: &str) -> Result<Range, ParseError> {
+    let mut numbers = line.split_whitespace();
+
+    Ok(Range {
+        dst: parse::number(parse::next(&mut numbers, line, "a destination")?)?,
+        start: parse::number(parse::next(&mut numbers, line, "a start")?)?,
+        len: parse::number(parse::next(&mut numbers, line, "a length")?)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl From<(u64, u64, u64)> for Range {
+        fn from(tuple: (u64, u64, u64)) -> Self {
+            Self {
+                dst: tuple.0,
+                start: tuple.1,
+                len: tuple.2,
+            }
+        }
+    }
+
+    fn get_input() -> <Solver as crate::Solver>::Input {
+        let seeds = vec![79, 14, 55, 13];
+        let ranges = [
+            vec![(50, 98, 2).into(), (52, 50, 48).into()],
+            vec![(0, 15, 37).into(), (37, 52, 2).into(), (39, 0, 15).into()],
+            vec![
+                (49, 53, 8).into(),
+                (0, 11, 42).into(),
+                (42, 0, 7).into(),
+                (57, 7, 4).into(),
+            ],
+            vec![(88, 18, 7).into(), (18, 25, 70).into()],
+            vec![
+                (45, 77, 23).into(),
+                (81, 45, 19).into(),
+                (68, 64, 13).into(),
+            ],
+            vec![(0, 69, 1).into(), (1, 0, 69).into()],
+            vec![(60, 56, 37).into(), (56, 93, 4).into()],
+        ];
+
+        let mut maps = [
+            Map::new(),
+            Map::new(),
+            Map::new(),
+            Map::new(),
+            Map::new(),
+            Map::new(),
+            Map::new(),
+        ];
+
+        for i in 0..7 {
+            for range in &ranges[i] {
+                maps[i].add(*range);
+            }
+        }
+
+        dbg!(&maps);
+
+        (seeds, maps)
+    }
+
+    #[test]
+    fn parsing() {
+        let input = r"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(<Solver as crate::Solver>::part_1(&get_input()), Ok(35));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(<Solver as crate::Solver>::part_2(&get_input()), Ok(46));
+    }
+}
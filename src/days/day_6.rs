@@ -0,0 +1,179 @@
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+use crate::{Answer, Unsolved};
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+#[derive(Debug, PartialEq)]
+struct Race {
+    time: u64,
+    record: u64,
+}
+
+impl Race {
+    fn get_num_winning_strategies(&self) -> usize {
+        // The distance can exceed `u64` for the long race of part 2.
+        (1..self.time)
+            .map(|hold_time| u128::from(hold_time) * u128::from(self.time - hold_time))
+            .filter(|&distance| distance > u128::from(self.record))
+            .count()
+    }
+}
+
+impl From<(u64, u64)> for Race {
+    fn from(tuple: (u64, u64)) -> Self {
+        Self {
+            time: tuple.0,
+            record: tuple.1,
+        }
+    }
+}
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 6;
+
+    type Input = Vec<Race>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut line_iter = input.lines();
+
+        let times_line = parse::next(&mut line_iter, input, "a line of times")?;
+        let records_line = parse::next(&mut line_iter, input, "a line of records")?;
+        let times = parse_line(times_line)?;
+        let records = parse_line(records_line)?;
+
+        // Every race has a time and a record.
+        if times.len() != records.len() {
+            let (shorter, count) = if times.len() < records.len() {
+                (times_line, records.len())
+            } else {
+                (records_line, times.len())
+            };
+            return Err(ParseError::new(
+                shorter,
+                format!("expected {count} numbers"),
+            ));
+        }
+
+        Ok(times
+            .into_iter()
+            .zip(records)
+            .map(|(time, record)| Race::from((time, record)))
+            .collect())
+    }
+
+    fn part_1(input: &Self::Input) -> Answer<Self::Output1> {
+        if input.is_empty() {
+            return Err(Unsolved::NoSolution);
+        }
+
+        Ok(input
+            .iter()
+            .map(|race| race.get_num_winning_strategies())
+            .product())
+    }
+
+    fn part_2(input: &Self::Input) -> Answer<Self::Output2> {
+        let total_time_string = input
+            .iter()
+            .fold(String::new(), |acc, race| acc + &race.time.to_string());
+        let total_record_string = input
+            .iter()
+            .fold(String::new(), |acc, race| acc + &race.record.to_string());
+
+        // Without races there is nothing to join, and the joined numbers may not fit.
+        let mega_race = Race {
+            time: u64::from_str(&total_time_string).map_err(|_| Unsolved::NoSolution)?,
+            record: u64::from_str(&total_record_string).map_err(|_| Unsolved::NoSolution)?,
+        };
+
+        Ok(mega_race.get_num_winning_strategies())
+    }
+}
+
+fn parse_line(line: &str) -> Result<Vec<u64>, ParseError> {
+    let (_, numbers) = parse::split_once(line, ":")?;
+    parse::numbers(numbers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> <Solver as crate::Solver>::Input {
+        vec![
+            Race { time: 7, record: 9 },
+            Race {
+                time: 15,
+                record: 40,
+            },
+            Race {
+                time: 30,
+                record: 200,
+            },
+        ]
+    }
+
+    #[test]
+    fn parsing() {
+        let input = r"Time:      7  15   30
+Distance:  9  40  200
+";
+
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
+        assert!(<Solver as crate::Solver>::parse("Time: 7 1x5\nDistance: 9 40").is_err());
+    }
+
+    #[test]
+    fn mismatched_counts() {
+        let input = "Time: 7 15 30\nDistance: 9 40";
+        let error = <Solver as crate::Solver>::parse(input)
+            .unwrap_err()
+            .locate(input);
+        assert_eq!(error.text(), "Distance: 9 40");
+        assert_eq!(error.line(), Some(2));
+
+        let input = "Time: 7\nDistance: 9 40";
+        let error = <Solver as crate::Solver>::parse(input)
+            .unwrap_err()
+            .locate(input);
+        assert_eq!(error.text(), "Time: 7");
+        assert_eq!(error.line(), Some(1));
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(<Solver as crate::Solver>::part_1(&get_input()), Ok(288));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(<Solver as crate::Solver>::part_2(&get_input()), Ok(71503));
+    }
+
+    #[test]
+    fn no_solution() {
+        let input = <Solver as crate::Solver>::parse("Time:\nDistance:\n").unwrap();
+        assert_eq!(
+            <Solver as crate::Solver>::part_1(&input),
+            Err(Unsolved::NoSolution)
+        );
+        assert_eq!(
+            <Solver as crate::Solver>::part_2(&input),
+            Err(Unsolved::NoSolution)
+        );
+
+        let input =
+            <Solver as crate::Solver>::parse("Time: 1000000000 1000000000 1\nDistance: 1 1 1")
+                .unwrap();
+        assert_eq!(
+            <Solver as crate::Solver>::part_2(&input),
+            Err(Unsolved::NoSolution)
+        );
+    }
+}
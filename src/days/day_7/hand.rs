@@ -8,6 +8,7 @@ pub struct Hand {
 }
 
 impl Hand {
+    #[allow(clippy::nonminimal_bool)]
     pub fn get_type(&self) -> Type {
         let mut cards = self.cards;
         cards.sort();
@@ -3,10 +3,14 @@ mod hand;
 use hand::Hand;
 
 use std::cmp::Ordering;
-use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+use crate::Answer;
 
 struct Solver;
 
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialOrd, PartialEq)]
 enum Card {
     C2,
@@ -24,9 +28,11 @@ enum Card {
     A,
 }
 
-impl From<char> for Card {
-    fn from(c: char) -> Self {
-        match c {
+impl TryFrom<char> for Card {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
             'A' => Self::A,
             'K' => Self::K,
             'Q' => Self::Q,
@@ -40,8 +46,8 @@ impl From<char> for Card {
             '4' => Self::C4,
             '3' => Self::C3,
             '2' => Self::C2,
-            _ => panic!(),
-        }
+            _ => return Err(()),
+        })
     }
 }
 
@@ -51,26 +57,32 @@ struct Game {
     bid: u64,
 }
 
-impl aoc::Solver for Solver {
+impl crate::Solver for Solver {
+    const DAY: u8 = 7;
+
     type Input = Vec<Game>;
     type Output1 = u64;
     type Output2 = u64;
 
-    fn parse(input: &str) -> Self::Input {
-        input.lines().map(|line| parse_line(line)).collect()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(parse_line).collect()
     }
 
-    fn part_1(input: &Self::Input) -> Self::Output1 {
-        get_total_winnings(input, |a: &Game, b: &Game| a.hand.cmp(&b.hand))
+    fn part_1(input: &Self::Input) -> Answer<Self::Output1> {
+        Ok(get_total_winnings(input, |a: &Game, b: &Game| {
+            a.hand.cmp(&b.hand)
+        }))
     }
 
-    fn part_2(input: &Self::Input) -> Self::Output2 {
-        get_total_winnings(input, |a: &Game, b: &Game| a.hand.jcmp(&b.hand))
+    fn part_2(input: &Self::Input) -> Answer<Self::Output2> {
+        Ok(get_total_winnings(input, |a: &Game, b: &Game| {
+            a.hand.jcmp(&b.hand)
+        }))
     }
 }
 
 fn get_total_winnings<F: Fn(&Game, &Game) -> Ordering>(
-    input: &<Solver as aoc::Solver>::Input,
+    input: &<Solver as crate::Solver>::Input,
     comparison: F,
 ) -> u64 {
     let mut input = input.clone();
@@ -82,29 +94,27 @@ fn get_total_winnings<F: Fn(&Game, &Game) -> Ordering>(
         .sum()
 }
 
-fn parse_line(line: &str) -> Game {
+fn parse_line(line: &str) -> Result<Game, ParseError> {
     let mut splits = line.split_whitespace();
 
-    let hand = splits.next().unwrap();
-    let cards: Vec<Card> = hand.chars().map(|c| Card::from(c)).collect();
+    let hand = parse::next(&mut splits, line, "a hand")?;
+    let cards: Vec<Card> = parse::chars(hand)?;
+    let cards = <[Card; 5]>::try_from(cards)
+        .map_err(|_| ParseError::new(hand, "expected a hand of five cards"))?;
 
-    let bid = u64::from_str(splits.next().unwrap()).unwrap();
+    let bid = parse::number(parse::next(&mut splits, line, "a bid")?)?;
 
-    Game {
-        hand: Hand::from(<[Card; 5]>::try_from(cards).unwrap()),
+    Ok(Game {
+        hand: Hand::from(cards),
         bid,
-    }
-}
-
-fn main() {
-    aoc::run::<Solver>();
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    fn get_input() -> <Solver as aoc::Solver>::Input {
+    fn get_input() -> <Solver as crate::Solver>::Input {
         vec![
             Game {
                 hand: Hand::from([Card::C3, Card::C2, Card::T, Card::C3, Card::K]),
@@ -137,16 +147,16 @@ KK677 28
 KTJJT 220
 QQQJA 483";
 
-        assert_eq!(<Solver as aoc::Solver>::parse(input), get_input());
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
     }
 
     #[test]
     fn part_1() {
-        assert_eq!(<Solver as aoc::Solver>::part_1(&get_input()), 6440);
+        assert_eq!(<Solver as crate::Solver>::part_1(&get_input()), Ok(6440));
     }
 
     #[test]
     fn part_2() {
-        assert_eq!(<Solver as aoc::Solver>::part_2(&get_input()), 5905);
+        assert_eq!(<Solver as crate::Solver>::part_2(&get_input()), Ok(5905));
     }
 }
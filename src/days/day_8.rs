@@ -0,0 +1,190 @@
+use std::collections::HashSet;
+
+use crate::graph::{Interner, NodeId};
+use crate::parse::{self, ParseError};
+use crate::Answer;
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Turn {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Turn {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'L' => Ok(Self::Left),
+            'R' => Ok(Self::Right),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The node names, and the left and right node of every node, indexed by [`NodeId`].
+#[derive(Debug, PartialEq)]
+struct Network {
+    names: Interner,
+    next: Vec<[NodeId; 2]>,
+}
+
+/// Builds the network from `(name, left, right)` triples. Every node that is referenced has to
+/// be defined exactly once.
+fn build_network<'a>(nodes: impl IntoIterator<Item = [&'a str; 3]>) -> Result<Network, ParseError> {
+    let mut names = Interner::new();
+    let mut next = vec![];
+    let mut references = vec![];
+
+    for [name, left, right] in nodes {
+        let [id, left_id, right_id] = [name, left, right].map(|name| names.intern(name));
+        next.resize(names.len(), None);
+
+        if next[id.index()].replace([left_id, right_id]).is_some() {
+            return Err(ParseError::new(name, "node is defined twice"));
+        }
+
+        references.extend([(left, left_id), (right, right_id)]);
+    }
+
+    if let Some((name, _)) = references.iter().find(|(_, id)| next[id.index()].is_none()) {
+        return Err(ParseError::new(name, "node is not defined"));
+    }
+
+    // Every node is either defined or referenced, so all of them are defined now.
+    Ok(Network {
+        names,
+        next: next.into_iter().flatten().collect(),
+    })
+}
+
+/// Follows `turns` from `node`.
+fn get_dest(network: &Network, node: NodeId, turns: &[Turn]) -> NodeId {
+    turns.iter().fold(node, |node, &turn| {
+        network.next[node.index()][turn as usize]
+    })
+}
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 8;
+
+    type Input = (Vec<Turn>, Network);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut sections = parse::Sections::new(input);
+
+        let turns = sections.next("turns", parse::chars)?;
+        let network = sections.next("nodes", |section| {
+            let nodes = section
+                .lines()
+                .map(|line| parse::pattern(line, "{name} = ({left}, {right})"))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            build_network(nodes)
+        })?;
+        sections.finish()?;
+
+        Ok((turns, network))
+    }
+
+    fn part_1(input: &Self::Input) -> Answer<Self::Output1> {
+        let (turns, network) = input;
+
+        Ok(get_num_steps(turns, network, |name| name == "AAA"))
+    }
+
+    fn part_2(input: &Self::Input) -> Answer<Self::Output2> {
+        let (turns, network) = input;
+
+        Ok(get_num_steps(turns, network, |name| name.ends_with('A')))
+    }
+}
+
+fn get_num_steps(turns: &[Turn], network: &Network, is_start: impl Fn(&str) -> bool) -> usize {
+    turns.len()
+        * network
+            .names
+            .iter()
+            .filter(|(_, name)| is_start(name))
+            .map(|(start, _)| {
+                let mut current = start;
+                let mut cycles = HashSet::new();
+
+                while !cycles.contains(&current) {
+                    cycles.insert(current);
+                    current = get_dest(network, current, turns);
+                }
+
+                cycles.len() - 1
+            })
+            .product::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> <Solver as crate::Solver>::Input {
+        (
+            vec![Turn::Left, Turn::Left, Turn::Right],
+            build_network([
+                ["AAA", "BBB", "BBB"],
+                ["BBB", "AAA", "ZZZ"],
+                ["ZZZ", "ZZZ", "ZZZ"],
+            ])
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn parsing() {
+        let input = r"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
+        assert_eq!(
+            <Solver as crate::Solver>::parse(&input.replace('\n', "\r\n")),
+            Ok(get_input())
+        );
+        assert!(<Solver as crate::Solver>::parse("L\n\nAAA = (BBB, AAA)").is_err());
+        assert!(
+            <Solver as crate::Solver>::parse("L\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)").is_err()
+        );
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(<Solver as crate::Solver>::part_1(&get_input()), Ok(6));
+    }
+
+    fn get_input_2() -> <Solver as crate::Solver>::Input {
+        (
+            vec![Turn::Left, Turn::Right],
+            build_network([
+                ["11A", "11B", "XXX"],
+                ["11B", "XXX", "11Z"],
+                ["11Z", "11B", "XXX"],
+                ["22A", "22B", "XXX"],
+                ["22B", "22C", "22C"],
+                ["22C", "22Z", "22Z"],
+                ["22Z", "22B", "22B"],
+                ["XXX", "XXX", "XXX"],
+            ])
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(<Solver as crate::Solver>::part_2(&get_input_2()), Ok(6));
+    }
+}
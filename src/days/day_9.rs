@@ -0,0 +1,166 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::parse::{self, ParseError};
+use crate::Answer;
+
+#[derive(Debug, PartialEq)]
+pub struct Sequence(Vec<i64>);
+
+impl Sequence {
+    pub fn extend(&self) -> Self {
+        if self.is_zero() {
+            let mut sequence = self.0.clone();
+            sequence.push(0);
+            Self(sequence)
+        } else {
+            let child = Self(self.get_differences());
+            let extended_child = child.extend();
+            let mut sequence = self.0.clone();
+            sequence.push(*sequence.last().unwrap() + extended_child.last());
+            Self(sequence)
+        }
+    }
+
+    pub fn pre_extend(&self) -> Self {
+        if self.is_zero() {
+            let mut sequence = self.0.clone();
+            sequence.insert(0, 0);
+            Self(sequence)
+        } else {
+            let child = Self(self.get_differences());
+            let extended_child = child.pre_extend();
+            let mut sequence = self.0.clone();
+            sequence.insert(0, *sequence.first().unwrap() - extended_child.first());
+            Self(sequence)
+        }
+    }
+
+    pub fn last(&self) -> i64 {
+        *self.0.last().unwrap()
+    }
+
+    pub fn first(&self) -> i64 {
+        *self.0.first().unwrap()
+    }
+
+    fn get_differences(&self) -> Vec<i64> {
+        self.0
+            .windows(2)
+            .map(|window| window[1] - window[0])
+            .collect()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.iter().all(|val| *val == 0)
+    }
+}
+
+impl TryFrom<&str> for Sequence {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        Ok(Self(
+            line.split_whitespace()
+                .map(parse::number)
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl Display for Sequence {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        for val in self.0.iter() {
+            write!(f, "{val:8} ")?;
+        }
+
+        writeln!(f)?;
+
+        if !self.is_zero() {
+            let mut indentation = 0;
+            let mut child_sequence = Self(self.get_differences());
+
+            loop {
+                indentation += 4;
+                write!(f, "{:indentation$}", ' ')?;
+
+                for val in child_sequence.0.iter() {
+                    write!(f, "{val:8} ")?;
+                }
+
+                writeln!(f)?;
+
+                if child_sequence.is_zero() {
+                    break;
+                }
+
+                child_sequence = Self(child_sequence.get_differences());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+struct Solver;
+
+pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+
+impl crate::Solver for Solver {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Sequence>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(Sequence::try_from).collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Answer<Self::Output1> {
+        Ok(input
+            .iter()
+            .map(|sequence| sequence.extend())
+            .map(|sequence| sequence.last())
+            .sum())
+    }
+
+    fn part_2(input: &Self::Input) -> Answer<Self::Output2> {
+        Ok(input
+            .iter()
+            .map(|sequence| sequence.pre_extend())
+            .map(|sequence| sequence.first())
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> <Solver as crate::Solver>::Input {
+        vec![
+            Sequence(vec![0, 3, 6, 9, 12, 15]),
+            Sequence(vec![1, 3, 6, 10, 15, 21]),
+            Sequence(vec![10, 13, 16, 21, 30, 45]),
+        ]
+    }
+
+    #[test]
+    fn parsing() {
+        let input = r"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+        assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(<Solver as crate::Solver>::part_1(&get_input()), Ok(114));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(<Solver as crate::Solver>::part_2(&get_input()), Ok(2));
+    }
+}
@@ -0,0 +1,61 @@
+//! Solvers for the individual days, and a registry to look them up by day number.
+
+mod day_1;
+mod day_10;
+mod day_11;
+mod day_12;
+mod day_13;
+mod day_14;
+mod day_15;
+mod day_16;
+mod day_17;
+mod day_18;
+mod day_19;
+mod day_2;
+mod day_20;
+mod day_21;
+mod day_22;
+mod day_23;
+mod day_24;
+mod day_25;
+mod day_3;
+mod day_4;
+mod day_5;
+mod day_6;
+mod day_7;
+mod day_8;
+mod day_9;
+
+use crate::Day;
+
+pub(crate) const DAYS: [Day; 25] = [
+    day_1::SOLVER,
+    day_2::SOLVER,
+    day_3::SOLVER,
+    day_4::SOLVER,
+    day_5::SOLVER,
+    day_6::SOLVER,
+    day_7::SOLVER,
+    day_8::SOLVER,
+    day_9::SOLVER,
+    day_10::SOLVER,
+    day_11::SOLVER,
+    day_12::SOLVER,
+    day_13::SOLVER,
+    day_14::SOLVER,
+    day_15::SOLVER,
+    day_16::SOLVER,
+    day_17::SOLVER,
+    day_18::SOLVER,
+    day_19::SOLVER,
+    day_20::SOLVER,
+    day_21::SOLVER,
+    day_22::SOLVER,
+    day_23::SOLVER,
+    day_24::SOLVER,
+    day_25::SOLVER,
+];
+
+pub(crate) fn get(day: u8) -> Option<Day> {
+    DAYS.iter().find(|solver| solver.day() == day).copied()
+}
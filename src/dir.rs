@@ -1,4 +1,8 @@
-#[derive(Clone, Copy, Debug, PartialEq)]
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// One of the four orthogonal directions on a grid, where north is towards the first row.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Dir {
     N,
     E,
@@ -7,6 +11,9 @@ pub enum Dir {
 }
 
 impl Dir {
+    /// All directions, clockwise from north.
+    pub const ALL: [Dir; 4] = [Dir::N, Dir::E, Dir::S, Dir::W];
+
     pub fn right(self) -> Self {
         match self {
             Dir::N => Dir::E,
@@ -24,4 +31,233 @@ impl Dir {
             Dir::W => Dir::S,
         }
     }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Dir::N => Dir::S,
+            Dir::E => Dir::W,
+            Dir::S => Dir::N,
+            Dir::W => Dir::E,
+        }
+    }
+
+    /// The `(dx, dy)` offset of a step in this direction.
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Dir::N => (0, -1),
+            Dir::E => (1, 0),
+            Dir::S => (0, 1),
+            Dir::W => (-1, 0),
+        }
+    }
+
+    /// Converts an arrow: `^`, `>`, `v` or `<`.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Dir::N),
+            '>' => Some(Dir::E),
+            'v' => Some(Dir::S),
+            '<' => Some(Dir::W),
+            _ => None,
+        }
+    }
+
+    /// Converts a compass point: `N`, `E`, `S` or `W`.
+    pub fn from_compass(c: char) -> Option<Self> {
+        match c {
+            'N' => Some(Dir::N),
+            'E' => Some(Dir::E),
+            'S' => Some(Dir::S),
+            'W' => Some(Dir::W),
+            _ => None,
+        }
+    }
+
+    /// Converts up, right, down or left: `U`, `R`, `D` or `L`.
+    pub fn from_udlr(c: char) -> Option<Self> {
+        match c {
+            'U' => Some(Dir::N),
+            'R' => Some(Dir::E),
+            'D' => Some(Dir::S),
+            'L' => Some(Dir::W),
+            _ => None,
+        }
+    }
+}
+
+/// Accepts arrows (`^>v<`), compass points (`NESW`) and up, right, down and left (`URDL`), which
+/// do not share any characters. Use [`Dir::from_arrow`], [`Dir::from_compass`] or
+/// [`Dir::from_udlr`] when the other letters mean something else in the input, such as `S` for
+/// a start.
+impl TryFrom<char> for Dir {
+    type Error = InvalidDir;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Dir::from_arrow(c)
+            .or_else(|| Dir::from_compass(c))
+            .or_else(|| Dir::from_udlr(c))
+            .ok_or(InvalidDir(c))
+    }
+}
+
+/// The error for a character that is not a direction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvalidDir(pub char);
+
+impl Display for InvalidDir {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        write!(fmt, "{:?} is not a direction", self.0)
+    }
+}
+
+impl Error for InvalidDir {}
+
+/// One of the eight orthogonal and diagonal directions on a grid.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    /// All directions, clockwise from north.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    /// Turns 45 degrees clockwise.
+    pub fn right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns 45 degrees counterclockwise.
+    pub fn left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Converts a compass point: `N`, `NE`, `E`, `SE`, `S`, `SW`, `W` or `NW`.
+    pub fn from_compass(text: &str) -> Option<Self> {
+        match text {
+            "N" => Some(Dir8::N),
+            "NE" => Some(Dir8::NE),
+            "E" => Some(Dir8::E),
+            "SE" => Some(Dir8::SE),
+            "S" => Some(Dir8::S),
+            "SW" => Some(Dir8::SW),
+            "W" => Some(Dir8::W),
+            "NW" => Some(Dir8::NW),
+            _ => None,
+        }
+    }
+
+    /// The `(dx, dy)` offset of a step in this direction.
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Dir8::N => (0, -1),
+            Dir8::NE => (1, -1),
+            Dir8::E => (1, 0),
+            Dir8::SE => (1, 1),
+            Dir8::S => (0, 1),
+            Dir8::SW => (-1, 1),
+            Dir8::W => (-1, 0),
+            Dir8::NW => (-1, -1),
+        }
+    }
+}
+
+/// Accepts the notations of [`Dir`] for the orthogonal directions, and the arrows `↑↗→↘↓↙←↖`.
+impl TryFrom<char> for Dir8 {
+    type Error = InvalidDir;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '↑' => Ok(Dir8::N),
+            '↗' => Ok(Dir8::NE),
+            '→' => Ok(Dir8::E),
+            '↘' => Ok(Dir8::SE),
+            '↓' => Ok(Dir8::S),
+            '↙' => Ok(Dir8::SW),
+            '←' => Ok(Dir8::W),
+            '↖' => Ok(Dir8::NW),
+            c => Dir::try_from(c).map(Dir8::from),
+        }
+    }
+}
+
+impl From<Dir> for Dir8 {
+    fn from(dir: Dir) -> Self {
+        match dir {
+            Dir::N => Dir8::N,
+            Dir::E => Dir8::E,
+            Dir::S => Dir8::S,
+            Dir::W => Dir8::W,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dir() {
+        for dir in Dir::ALL {
+            assert_eq!(dir.right().left(), dir);
+            assert_eq!(dir.right().right(), dir.opposite());
+
+            let (dx, dy) = dir.delta();
+            assert_eq!(dir.opposite().delta(), (-dx, -dy));
+        }
+
+        assert_eq!(Dir::from_arrow('^'), Some(Dir::N));
+        assert_eq!(Dir::from_arrow('<'), Some(Dir::W));
+        assert_eq!(Dir::from_arrow('N'), None);
+        assert_eq!(Dir::from_compass('E'), Some(Dir::E));
+        assert_eq!(Dir::from_compass('U'), None);
+        assert_eq!(Dir::from_udlr('D'), Some(Dir::S));
+        assert_eq!(Dir::from_udlr('S'), None);
+
+        assert_eq!(Dir::try_from('v'), Ok(Dir::S));
+        assert_eq!(Dir::try_from('W'), Ok(Dir::W));
+        assert_eq!(Dir::try_from('U'), Ok(Dir::N));
+        assert_eq!(Dir::try_from('x'), Err(InvalidDir('x')));
+    }
+
+    #[test]
+    fn dir8() {
+        for dir in Dir8::ALL {
+            assert_eq!(dir.right().left(), dir);
+            assert_eq!(dir.right().right().right().right(), dir.opposite());
+
+            let (dx, dy) = dir.delta();
+            assert_eq!(dir.opposite().delta(), (-dx, -dy));
+        }
+
+        for dir in Dir::ALL {
+            assert_eq!(Dir8::from(dir).delta(), dir.delta());
+        }
+
+        assert_eq!(Dir8::try_from('↘'), Ok(Dir8::SE));
+        assert_eq!(Dir8::try_from('<'), Ok(Dir8::W));
+        assert_eq!(Dir8::try_from('x'), Err(InvalidDir('x')));
+        assert_eq!(Dir8::from_compass("NW"), Some(Dir8::NW));
+        assert_eq!(Dir8::from_compass("NS"), None);
+    }
 }
@@ -0,0 +1,69 @@
+//! Formatting of the durations that the runner reports.
+
+use std::time::Duration;
+
+/// How durations are formatted in the output.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Units {
+    /// The largest unit of ns, µs, ms and s that keeps the value at least 1, rounded to three
+    /// significant digits (for example `12.3 ms`).
+    #[default]
+    Adaptive,
+    /// A plain number of nanoseconds, for other programs to consume.
+    Nanos,
+}
+
+impl Units {
+    pub fn format(self, duration: Duration) -> String {
+        let nanos = duration.as_nanos();
+
+        match self {
+            Units::Nanos => nanos.to_string(),
+            Units::Adaptive if nanos < 1_000 => format!("{nanos} ns"),
+            Units::Adaptive => {
+                // Values that would round to 1000 are shown in the next unit instead.
+                let (scale, unit) = [(1e3, "µs"), (1e6, "ms"), (1e9, "s")]
+                    .into_iter()
+                    .rev()
+                    .find(|(scale, _)| nanos as f64 >= scale * 0.9995)
+                    .unwrap();
+                let value = nanos as f64 / scale;
+
+                let precision = match value {
+                    value if value < 9.995 => 2,
+                    value if value < 99.95 => 1,
+                    _ => 0,
+                };
+
+                format!("{value:.precision$} {unit}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adaptive() {
+        let format = |nanos| Units::Adaptive.format(Duration::from_nanos(nanos));
+
+        assert_eq!(format(0), "0 ns");
+        assert_eq!(format(999), "999 ns");
+        assert_eq!(format(1_000), "1.00 µs");
+        assert_eq!(format(12_345), "12.3 µs");
+        assert_eq!(format(999_400), "999 µs");
+        assert_eq!(format(999_600), "1.00 ms");
+        assert_eq!(format(2_500_000_000), "2.50 s");
+        assert_eq!(format(125_000_000_000), "125 s");
+    }
+
+    #[test]
+    fn nanos() {
+        assert_eq!(
+            Units::Nanos.format(Duration::from_millis(2_500)),
+            "2500000000"
+        );
+    }
+}
@@ -0,0 +1,474 @@
+//! Directed graphs with compact node identifiers, and the usual traversals over them.
+//!
+//! Puzzles usually name their nodes, like `AAA = (BBB, CCC)`. An [`Interner`] turns those names
+//! into [`NodeId`]s once while parsing, so that solvers work with small copyable identifiers
+//! instead of cloning and hashing strings on every step.
+
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+/// Identifies a node of a [`Graph`] or a name in an [`Interner`]. Identifiers are handed out
+/// in order, starting at 0.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NodeId(u32);
+
+impl NodeId {
+    fn new(index: usize) -> Self {
+        Self(u32::try_from(index).expect("too many nodes"))
+    }
+
+    /// The position of the node in the order in which the nodes were added.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Assigns a [`NodeId`] to every distinct name, in order of first appearance.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Interner {
+    ids: HashMap<String, NodeId>,
+    names: Vec<String>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The identifier of `name`, which is assigned if `name` has not been seen before.
+    pub fn intern(&mut self, name: &str) -> NodeId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+
+        let id = NodeId::new(self.names.len());
+        self.ids.insert(name.to_string(), id);
+        self.names.push(name.to_string());
+        id
+    }
+
+    /// The identifier of `name`, if it has been interned.
+    pub fn get(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id.index()]
+    }
+
+    /// All names, indexed by their identifier.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// The names with their identifiers.
+    pub fn iter(&self) -> impl Iterator<Item = (NodeId, &str)> {
+        self.names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| (NodeId::new(index), name.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+/// A directed graph stored as adjacency lists, with a value of type `N` on every node and a
+/// value of type `E` on every edge.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Graph<N, E> {
+    nodes: Vec<N>,
+    edges: Vec<Vec<(NodeId, E)>>,
+}
+
+impl<N, E> Default for Graph<N, E> {
+    fn default() -> Self {
+        Self {
+            nodes: vec![],
+            edges: vec![],
+        }
+    }
+}
+
+impl<N, E> Graph<N, E> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_node(&mut self, node: N) -> NodeId {
+        let id = NodeId::new(self.nodes.len());
+        self.nodes.push(node);
+        self.edges.push(vec![]);
+        id
+    }
+
+    /// Adds an edge from `from` to `to`. Parallel edges and self-loops are allowed.
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, edge: E) {
+        assert!(to.index() < self.nodes.len(), "{to:?} is not in the graph");
+        self.edges[from.index()].push((to, edge));
+    }
+
+    pub fn node(&self, id: NodeId) -> &N {
+        &self.nodes[id.index()]
+    }
+
+    pub fn node_mut(&mut self, id: NodeId) -> &mut N {
+        &mut self.nodes[id.index()]
+    }
+
+    /// The number of nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn node_ids(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.nodes.len()).map(NodeId::new)
+    }
+
+    /// The nodes with their identifiers.
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeId, &N)> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (NodeId::new(index), node))
+    }
+
+    /// The outgoing edges of `id`, in the order in which they were added.
+    pub fn edges(&self, id: NodeId) -> &[(NodeId, E)] {
+        &self.edges[id.index()]
+    }
+
+    /// The targets of the outgoing edges of `id`.
+    pub fn successors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges[id.index()].iter().map(|&(to, _)| to)
+    }
+
+    /// The nodes reachable from `start` in breadth-first order, starting with `start`.
+    pub fn bfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut visited = vec![false; self.len()];
+        let mut order = vec![];
+        let mut queue = VecDeque::from([start]);
+        visited[start.index()] = true;
+
+        while let Some(id) = queue.pop_front() {
+            order.push(id);
+
+            for next in self.successors(id) {
+                if !std::mem::replace(&mut visited[next.index()], true) {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        order
+    }
+
+    /// The nodes reachable from `start` in depth-first preorder, starting with `start`.
+    /// Successors are visited in the order of their edges.
+    pub fn dfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut visited = vec![false; self.len()];
+        let mut order = vec![];
+        let mut stack = vec![start];
+
+        while let Some(id) = stack.pop() {
+            if std::mem::replace(&mut visited[id.index()], true) {
+                continue;
+            }
+            order.push(id);
+
+            // Pushed in reverse, so that the first successor is visited first.
+            let edges = &self.edges[id.index()];
+            stack.extend(edges.iter().rev().map(|&(to, _)| to));
+        }
+
+        order
+    }
+
+    /// Orders the nodes so that every edge points from an earlier to a later node, or returns
+    /// `None` if the graph has a cycle.
+    pub fn topological_sort(&self) -> Option<Vec<NodeId>> {
+        let mut in_degrees = vec![0; self.len()];
+        for id in self.node_ids() {
+            for next in self.successors(id) {
+                in_degrees[next.index()] += 1;
+            }
+        }
+
+        let mut queue: VecDeque<_> = self
+            .node_ids()
+            .filter(|id| in_degrees[id.index()] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.len());
+
+        while let Some(id) = queue.pop_front() {
+            order.push(id);
+
+            for next in self.successors(id) {
+                in_degrees[next.index()] -= 1;
+                if in_degrees[next.index()] == 0 {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// Whether the graph has a cycle, including a self-loop.
+    pub fn has_cycle(&self) -> bool {
+        self.topological_sort().is_none()
+    }
+
+    /// The strongly connected components, which are the largest groups of nodes that can all
+    /// reach each other. Components come in reverse topological order: no component has an
+    /// edge to a component after it.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeId>> {
+        // Tarjan's algorithm, with an explicit stack of (node, index of the next edge) instead
+        // of recursion.
+        const UNVISITED: usize = usize::MAX;
+
+        let mut indices = vec![UNVISITED; self.len()];
+        let mut low_links = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = vec![];
+        let mut components = vec![];
+        let mut next_index = 0;
+
+        for root in self.node_ids() {
+            if indices[root.index()] != UNVISITED {
+                continue;
+            }
+
+            let mut call_stack = vec![(root, 0)];
+
+            while let Some(&mut (id, ref mut edge)) = call_stack.last_mut() {
+                let node = id.index();
+
+                if *edge == 0 {
+                    indices[node] = next_index;
+                    low_links[node] = next_index;
+                    next_index += 1;
+                    stack.push(id);
+                    on_stack[node] = true;
+                }
+
+                if let Some(&(next, _)) = self.edges[node].get(*edge) {
+                    *edge += 1;
+
+                    if indices[next.index()] == UNVISITED {
+                        call_stack.push((next, 0));
+                    } else if on_stack[next.index()] {
+                        low_links[node] = low_links[node].min(indices[next.index()]);
+                    }
+                    continue;
+                }
+
+                call_stack.pop();
+                if let Some(&(parent, _)) = call_stack.last() {
+                    low_links[parent.index()] = low_links[parent.index()].min(low_links[node]);
+                }
+
+                if low_links[node] == indices[node] {
+                    let mut component = vec![];
+                    loop {
+                        let member = stack.pop().unwrap();
+                        on_stack[member.index()] = false;
+                        component.push(member);
+                        if member == id {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+}
+
+impl<N, E> FromIterator<N> for Graph<N, E> {
+    /// Creates a graph with the given nodes and no edges.
+    fn from_iter<I: IntoIterator<Item = N>>(iter: I) -> Self {
+        let nodes: Vec<N> = iter.into_iter().collect();
+        let edges = nodes.iter().map(|_| vec![]).collect();
+
+        Self { nodes, edges }
+    }
+}
+
+/// A cut of a graph into two non-empty groups of nodes, as found by [`min_cut`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cut {
+    /// The total weight of the edges between the two groups.
+    pub weight: u64,
+    /// The nodes of one of the groups. The other group consists of the remaining nodes.
+    pub nodes: Vec<usize>,
+}
+
+/// Finds a global minimum cut of an undirected graph with weighted edges between the nodes
+/// `0..num_nodes`, using the Stoer–Wagner algorithm. Parallel edges add up, and self-loops are
+/// ignored. Returns `None` if the graph has fewer than two nodes.
+///
+/// Every phase of the algorithm grows a set of nodes from an arbitrary node, always adding the
+/// node that is most tightly connected to the set. The weight of the last node is then a cut
+/// between it and the rest, and the last two nodes are merged for the next phase.
+pub fn min_cut(num_nodes: usize, edges: &[(usize, usize, u64)]) -> Option<Cut> {
+    let mut adjacency: Vec<HashMap<usize, u64>> = vec![HashMap::new(); num_nodes];
+    for &(a, b, weight) in edges {
+        if a != b {
+            *adjacency[a].entry(b).or_default() += weight;
+            *adjacency[b].entry(a).or_default() += weight;
+        }
+    }
+
+    // The original nodes that each merged node stands for.
+    let mut members: Vec<Vec<usize>> = (0..num_nodes).map(|node| vec![node]).collect();
+    let mut active: Vec<usize> = (0..num_nodes).collect();
+    let mut best: Option<Cut> = None;
+
+    while active.len() > 1 {
+        let (s, t, weight) = min_cut_phase(&adjacency, &active);
+
+        if best.as_ref().is_none_or(|best| weight < best.weight) {
+            best = Some(Cut {
+                weight,
+                nodes: members[t].clone(),
+            });
+        }
+
+        // Merge t into s.
+        for (node, weight) in std::mem::take(&mut adjacency[t]) {
+            adjacency[node].remove(&t);
+            if node != s {
+                *adjacency[s].entry(node).or_default() += weight;
+                *adjacency[node].entry(s).or_default() += weight;
+            }
+        }
+
+        let merged = std::mem::take(&mut members[t]);
+        members[s].extend(merged);
+        active.retain(|&node| node != t);
+    }
+
+    best
+}
+
+/// Adds the `active` nodes one by one, most tightly connected first, and returns the last two
+/// nodes with the weight of the edges between the last node and all others.
+fn min_cut_phase(adjacency: &[HashMap<usize, u64>], active: &[usize]) -> (usize, usize, u64) {
+    let mut weights: HashMap<usize, u64> = active.iter().map(|&node| (node, 0)).collect();
+    let mut heap: BinaryHeap<(u64, usize)> = active.iter().map(|&node| (0, node)).collect();
+    let (mut s, mut t, mut cut) = (active[0], active[0], 0);
+
+    while let Some((weight, node)) = heap.pop() {
+        // The node was already added, or its weight has increased since this entry was pushed.
+        if weights.get(&node) != Some(&weight) {
+            continue;
+        }
+
+        weights.remove(&node);
+        (s, t, cut) = (t, node, weight);
+
+        for (&neighbor, &edge) in &adjacency[node] {
+            if let Some(weight) = weights.get_mut(&neighbor) {
+                *weight += edge;
+                heap.push((*weight, neighbor));
+            }
+        }
+    }
+
+    (s, t, cut)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_graph(num_nodes: usize, edges: &[(usize, usize)]) -> Graph<(), ()> {
+        let mut graph: Graph<(), ()> = std::iter::repeat_n((), num_nodes).collect();
+        for &(from, to) in edges {
+            graph.add_edge(NodeId::new(from), NodeId::new(to), ());
+        }
+        graph
+    }
+
+    fn ids(indices: &[usize]) -> Vec<NodeId> {
+        indices.iter().map(|&index| NodeId::new(index)).collect()
+    }
+
+    #[test]
+    fn interner() {
+        let mut interner = Interner::new();
+        let aaa = interner.intern("AAA");
+        let bbb = interner.intern("BBB");
+
+        assert_eq!(interner.intern("AAA"), aaa);
+        assert_eq!((aaa.index(), bbb.index()), (0, 1));
+        assert_eq!(interner.get("BBB"), Some(bbb));
+        assert_eq!(interner.get("CCC"), None);
+        assert_eq!(interner.name(bbb), "BBB");
+        assert_eq!(interner.len(), 2);
+        assert_eq!(
+            interner.iter().collect::<Vec<_>>(),
+            [(aaa, "AAA"), (bbb, "BBB")]
+        );
+    }
+
+    #[test]
+    fn traversals() {
+        // 0 -> 1 -> 3, 0 -> 2 -> 3 -> 4, and 5 is unreachable.
+        let graph = get_graph(6, &[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (5, 0)]);
+
+        assert_eq!(graph.bfs(NodeId::new(0)), ids(&[0, 1, 2, 3, 4]));
+        assert_eq!(graph.dfs(NodeId::new(0)), ids(&[0, 1, 3, 4, 2]));
+        assert_eq!(graph.topological_sort(), Some(ids(&[5, 0, 1, 2, 3, 4])));
+        assert!(!graph.has_cycle());
+    }
+
+    #[test]
+    fn cycles() {
+        let graph = get_graph(5, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3)]);
+
+        assert_eq!(graph.topological_sort(), None);
+        assert!(graph.has_cycle());
+        assert!(get_graph(1, &[(0, 0)]).has_cycle());
+
+        let mut components = graph.strongly_connected_components();
+        for component in &mut components {
+            component.sort();
+        }
+        assert_eq!(components, [ids(&[3, 4]), ids(&[0, 1, 2])]);
+    }
+
+    #[test]
+    fn two_triangles() {
+        // Two triangles, joined by two edges with a total weight of 3.
+        let edges = [
+            (0, 1, 3),
+            (1, 2, 3),
+            (2, 0, 3),
+            (3, 4, 3),
+            (4, 5, 3),
+            (5, 3, 3),
+            (0, 3, 2),
+            (1, 4, 1),
+        ];
+
+        let mut cut = min_cut(6, &edges).unwrap();
+        cut.nodes.sort();
+        assert_eq!(cut.weight, 3);
+        assert!(cut.nodes == [0, 1, 2] || cut.nodes == [3, 4, 5]);
+
+        assert_eq!(min_cut(1, &[]), None);
+        assert_eq!(min_cut(3, &[(0, 1, 1)]).map(|cut| cut.weight), Some(0));
+    }
+}
@@ -1,6 +1,15 @@
 use std::fmt::{self, Display, Formatter};
+use std::num::TryFromIntError;
+use std::ops::{Add, Sub};
 
-use crate::Dir;
+use crate::{Dir, ParseError};
+
+mod bits;
+mod sparse;
+mod tiled;
+pub use bits::{BitGrid, DirGrid};
+pub use sparse::SparseGrid;
+pub use tiled::TiledGrid;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct Pos {
@@ -33,8 +42,164 @@ impl Pos {
             },
         }
     }
+
+    /// Moves a step in `dir`, or returns `None` if that would leave `grid`.
+    pub fn checked_apply<T>(self, dir: Dir, grid: &Grid<T>) -> Option<Self> {
+        match dir {
+            Dir::N => Some(Self {
+                y: self.y.checked_sub(1)?,
+                ..self
+            }),
+            Dir::E => (self.x + 1 < grid.width).then(|| Self {
+                x: self.x + 1,
+                ..self
+            }),
+            Dir::S => (self.y + 1 < grid.height).then(|| Self {
+                y: self.y + 1,
+                ..self
+            }),
+            Dir::W => Some(Self {
+                x: self.x.checked_sub(1)?,
+                ..self
+            }),
+        }
+    }
+
+    /// Moves by `offset`, given as `(dx, dy)`, or returns `None` if that would leave `grid`.
+    pub fn checked_offset<T>(self, (dx, dy): (isize, isize), grid: &Grid<T>) -> Option<Self> {
+        let x = self.x.checked_add_signed(dx).filter(|&x| x < grid.width)?;
+        let y = self.y.checked_add_signed(dy).filter(|&y| y < grid.height)?;
+
+        Some(Self { x, y })
+    }
+
+    /// Moves a step in `dir`, wrapping around to the opposite edge when leaving `grid`, as if it
+    /// were tiled infinitely, or returns `None` if `grid` is empty and there is nowhere to go.
+    pub fn wrapping_apply<T>(self, dir: Dir, grid: &Grid<T>) -> Option<Self> {
+        if grid.width == 0 || grid.height == 0 {
+            return None;
+        }
+
+        let pos = match dir {
+            Dir::N => Self {
+                y: self.y.checked_sub(1).unwrap_or(grid.height - 1),
+                ..self
+            },
+            Dir::E => Self {
+                x: (self.x + 1) % grid.width,
+                ..self
+            },
+            Dir::S => Self {
+                y: (self.y + 1) % grid.height,
+                ..self
+            },
+            Dir::W => Self {
+                x: self.x.checked_sub(1).unwrap_or(grid.width - 1),
+                ..self
+            },
+        };
+
+        Some(pos)
+    }
+}
+
+/// A position on an unbounded plane, which unlike [`Pos`] can be negative.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct IPos {
+    pub x: isize,
+    pub y: isize,
 }
 
+impl IPos {
+    pub fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    /// Moves `distance` steps in `dir`. North is towards negative `y`, as for [`Pos`].
+    pub fn apply(self, dir: Dir, distance: isize) -> Self {
+        let (dx, dy) = dir.delta();
+        self.offset((dx * distance, dy * distance))
+    }
+
+    /// Moves by `offset`, given as `(dx, dy)`.
+    pub fn offset(self, (dx, dy): (isize, isize)) -> Self {
+        Self::new(self.x + dx, self.y + dy)
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The orthogonal neighbors, with their direction.
+    pub fn neighbors(self) -> impl Iterator<Item = (Dir, Self)> {
+        Dir::ALL
+            .into_iter()
+            .map(move |dir| (dir, self.apply(dir, 1)))
+    }
+
+    /// The orthogonal and diagonal neighbors, with their offset.
+    pub fn neighbors_8(self) -> impl Iterator<Item = ((isize, isize), Self)> {
+        self.neighbors_with(&NEIGHBORS_8)
+    }
+
+    /// The positions at the offsets in `stencil`, with their offset.
+    pub fn neighbors_with(
+        self,
+        stencil: &[(isize, isize)],
+    ) -> impl Iterator<Item = ((isize, isize), Self)> + '_ {
+        stencil
+            .iter()
+            .map(move |&offset| (offset, self.offset(offset)))
+    }
+}
+
+impl Add for IPos {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for IPos {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl TryFrom<Pos> for IPos {
+    type Error = TryFromIntError;
+
+    fn try_from(pos: Pos) -> Result<Self, Self::Error> {
+        Ok(Self::new(pos.x.try_into()?, pos.y.try_into()?))
+    }
+}
+
+impl TryFrom<IPos> for Pos {
+    type Error = TryFromIntError;
+
+    fn try_from(pos: IPos) -> Result<Self, Self::Error> {
+        Ok(Self::new(pos.x.try_into()?, pos.y.try_into()?))
+    }
+}
+
+/// The offsets `(dx, dy)` of the orthogonal neighbors, clockwise from north.
+pub const NEIGHBORS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The offsets `(dx, dy)` of the orthogonal and diagonal neighbors, clockwise from north.
+pub const NEIGHBORS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Grid<T> {
     grid: Vec<T>,
@@ -42,7 +207,7 @@ pub struct Grid<T> {
     height: usize,
 }
 
-impl<T: PartialEq> Grid<T> {
+impl<T> Grid<T> {
     pub fn get(&self, x: usize, y: usize) -> &T {
         let index = self.get_index(x, y);
         &self.grid[index]
@@ -61,6 +226,52 @@ impl<T: PartialEq> Grid<T> {
         self.height
     }
 
+    /// The cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.grid.iter()
+    }
+
+    /// The cells in row-major order, mutably.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.grid.iter_mut()
+    }
+
+    /// All positions in the grid in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Pos { x, y }))
+    }
+
+    /// The cells in row-major order, with their positions.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.iter())
+    }
+
+    /// The rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` does not accept 0, but a grid without columns has no cells anyway.
+        self.grid.chunks(self.width.max(1))
+    }
+
+    /// The columns from left to right, each iterating from top to bottom.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.grid[x..].iter().step_by(self.width))
+    }
+
+    /// A view that repeats the grid infinitely in every direction, or `None` if the grid is
+    /// empty and there is nothing to repeat.
+    pub fn tiled(&self) -> Option<TiledGrid<'_, T>> {
+        TiledGrid::new(self)
+    }
+
+    /// The position of the first cell in row-major order that matches `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.enumerate()
+            .find(|(_, item)| predicate(item))
+            .map(|(pos, _)| pos)
+    }
+
     fn get_index(&self, x: usize, y: usize) -> usize {
         debug_assert!(x < self.width);
         debug_assert!(y < self.height);
@@ -69,31 +280,32 @@ impl<T: PartialEq> Grid<T> {
     }
 
     pub fn get_neighbors(&self, pos: &Pos) -> Vec<Pos> {
-        let mut neighbors = vec![];
-
-        if let Some(x) = pos.x.checked_sub(1) {
-            neighbors.push(Pos { x, ..*pos });
-        }
-
-        if let Some(y) = pos.y.checked_sub(1) {
-            neighbors.push(Pos { y, ..*pos });
-        }
+        self.neighbors(*pos).map(|(_, pos)| pos).collect()
+    }
 
-        if pos.x < self.width - 1 {
-            neighbors.push(Pos {
-                x: pos.x + 1,
-                ..*pos
-            });
-        }
+    /// The orthogonal neighbors of `pos` that lie within the grid, with their direction.
+    pub fn neighbors(&self, pos: Pos) -> impl Iterator<Item = (Dir, Pos)> + '_ {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |dir| Some((dir, pos.checked_apply(dir, self)?)))
+    }
 
-        if pos.y < self.height - 1 {
-            neighbors.push(Pos {
-                y: pos.y + 1,
-                ..*pos
-            });
-        }
+    /// The orthogonal and diagonal neighbors of `pos` that lie within the grid, with their
+    /// offset from `pos`.
+    pub fn neighbors_8(&self, pos: Pos) -> impl Iterator<Item = ((isize, isize), Pos)> + '_ {
+        self.neighbors_with(pos, &NEIGHBORS_8)
+    }
 
-        neighbors
+    /// The positions at the offsets in `stencil` from `pos` that lie within the grid, with their
+    /// offset.
+    pub fn neighbors_with<'a>(
+        &'a self,
+        pos: Pos,
+        stencil: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = ((isize, isize), Pos)> + 'a {
+        stencil
+            .iter()
+            .filter_map(move |&offset| Some((offset, pos.checked_offset(offset, self)?)))
     }
 }
 
@@ -107,6 +319,300 @@ impl<T: Copy> Grid<T> {
     }
 }
 
+impl<T> Grid<T> {
+    /// Creates a grid by calling `f` for each position, in row-major order.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let grid = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Pos { x, y }))
+            .map(&mut f)
+            .collect();
+
+        Self {
+            grid,
+            width,
+            height,
+        }
+    }
+
+    /// A borrowed view of the `width` by `height` rectangle whose top-left corner is at
+    /// `origin`, which must lie within the grid.
+    pub fn view(&self, origin: Pos, width: usize, height: usize) -> View<'_, T> {
+        assert!(origin.x + width <= self.width && origin.y + height <= self.height);
+
+        View {
+            grid: self,
+            origin,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// The region of orthogonally connected cells matching `predicate` that contains `start`,
+    /// or `None` if `start` does not match.
+    pub fn flood_fill(&self, start: Pos, predicate: impl Fn(&T) -> bool) -> Option<Region> {
+        let mut labels = Grid::new(self.width, self.height, None);
+
+        self.fill(start, &predicate, &mut labels, 0)
+    }
+
+    /// Splits the cells matching `predicate` into regions of orthogonally connected cells.
+    pub fn components(&self, predicate: impl Fn(&T) -> bool) -> Components {
+        let mut labels = Grid::new(self.width, self.height, None);
+        let mut regions = vec![];
+
+        for pos in self.positions() {
+            if labels.get(pos.x, pos.y).is_none() {
+                regions.extend(self.fill(pos, &predicate, &mut labels, regions.len()));
+            }
+        }
+
+        Components { labels, regions }
+    }
+
+    /// Labels the cells of the region containing `start` with `label`, visiting each cell once.
+    fn fill(
+        &self,
+        start: Pos,
+        predicate: &impl Fn(&T) -> bool,
+        labels: &mut Grid<Option<usize>>,
+        label: usize,
+    ) -> Option<Region> {
+        if !predicate(self.get(start.x, start.y)) {
+            return None;
+        }
+
+        let mut region = Region {
+            positions: vec![],
+            min: start,
+            max: start,
+        };
+        let mut stack = vec![start];
+        *labels.get_mut(start.x, start.y) = Some(label);
+
+        while let Some(pos) = stack.pop() {
+            region.positions.push(pos);
+            region.min = Pos::new(region.min.x.min(pos.x), region.min.y.min(pos.y));
+            region.max = Pos::new(region.max.x.max(pos.x), region.max.y.max(pos.y));
+
+            for (_, next) in self.neighbors(pos) {
+                if labels.get(next.x, next.y).is_none() && predicate(self.get(next.x, next.y)) {
+                    *labels.get_mut(next.x, next.y) = Some(label);
+                    stack.push(next);
+                }
+            }
+        }
+
+        Some(region)
+    }
+}
+
+/// A region of orthogonally connected cells, as found by [`Grid::flood_fill`] and
+/// [`Grid::components`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Region {
+    positions: Vec<Pos>,
+    min: Pos,
+    max: Pos,
+}
+
+impl Region {
+    /// The positions in the region, in no particular order.
+    pub fn positions(&self) -> &[Pos] {
+        &self.positions
+    }
+
+    pub fn size(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// The top-left and bottom-right corners of the smallest rectangle containing the region.
+    pub fn bounding_box(&self) -> (Pos, Pos) {
+        (self.min, self.max)
+    }
+}
+
+/// The regions found by [`Grid::components`], and the region that each cell belongs to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Components {
+    labels: Grid<Option<usize>>,
+    regions: Vec<Region>,
+}
+
+impl Components {
+    /// The index in [`Components::regions`] of the region containing `pos`, or `None` if the
+    /// cell did not match.
+    pub fn label(&self, pos: Pos) -> Option<usize> {
+        *self.labels.get(pos.x, pos.y)
+    }
+
+    pub fn labels(&self) -> &Grid<Option<usize>> {
+        &self.labels
+    }
+
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Mirrors the grid along its main diagonal, so that rows become columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |Pos { x, y }| {
+            self.get(y, x).clone()
+        })
+    }
+
+    /// Rotates the grid a quarter turn clockwise, so that the west edge becomes the north edge.
+    pub fn rotate_cw(&self) -> Self {
+        Self::from_fn(self.height, self.width, |Pos { x, y }| {
+            self.get(y, self.height - 1 - x).clone()
+        })
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise, so that the east edge becomes the
+    /// north edge.
+    pub fn rotate_ccw(&self) -> Self {
+        Self::from_fn(self.height, self.width, |Pos { x, y }| {
+            self.get(self.width - 1 - y, x).clone()
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        Self::from_fn(self.width, self.height, |Pos { x, y }| {
+            self.get(self.width - 1 - x, y).clone()
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        Self::from_fn(self.width, self.height, |Pos { x, y }| {
+            self.get(x, self.height - 1 - y).clone()
+        })
+    }
+}
+
+/// A rectangular part of a [`Grid`], with its own coordinates starting at `(0, 0)`.
+#[derive(Clone, Copy, Debug)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Pos,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> View<'a, T> {
+    pub fn get(&self, x: usize, y: usize) -> &'a T {
+        debug_assert!(x < self.width);
+        debug_assert!(y < self.height);
+
+        self.grid.get(self.origin.x + x, self.origin.y + y)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let Pos { x, y } = self.origin;
+        let width = self.width;
+
+        self.grid
+            .rows()
+            .skip(y)
+            .take(self.height)
+            .map(move |row| &row[x..x + width])
+    }
+
+    /// The cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        self.rows().flatten()
+    }
+
+    /// The cells in row-major order, with their positions in the view.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Pos, &'a T)> {
+        let width = self.width;
+
+        (0..self.height)
+            .flat_map(move |y| (0..width).map(move |x| Pos { x, y }))
+            .zip(self.iter())
+    }
+
+    /// Copies the view into a grid of its own.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |Pos { x, y }| {
+            self.get(x, y).clone()
+        })
+    }
+}
+
+impl<T: TryFrom<char>> Grid<T> {
+    /// Parses a grid with a row per line, converting each character with [`TryFrom<char>`].
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::try_parse_with(input, T::try_from)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a grid with a row per line, converting each character with `convert`.
+    ///
+    /// Lines may end in `\n` or `\r\n`, and empty lines at the end are ignored. All rows must be
+    /// as wide as the first one. Errors are not located, so that `input` can be a slice of a
+    /// larger input that the caller locates them in.
+    pub fn try_parse_with<E>(
+        input: &str,
+        convert: impl Fn(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let mut grid = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for line in input.trim_end_matches(['\r', '\n']).lines() {
+            let mut row_width = 0;
+
+            for (index, c) in line.char_indices() {
+                if width == Some(row_width) {
+                    return Err(ParseError::new(&line[index..], "row is too long"));
+                }
+
+                let item = convert(c).map_err(|_| {
+                    ParseError::new(&line[index..index + c.len_utf8()], "unexpected character")
+                })?;
+                grid.push(item);
+                row_width += 1;
+            }
+
+            match width {
+                Some(width) if row_width < width => {
+                    return Err(ParseError::new(
+                        &line[line.len()..],
+                        format!("row is too short, expected {width} characters"),
+                    ));
+                }
+                _ => width = Some(row_width),
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            grid,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+}
+
 impl<T, I: IntoIterator<Item = T>> FromIterator<I> for Grid<T> {
     fn from_iter<II: IntoIterator<Item = I>>(iter: II) -> Self {
         let mut width = 0;
@@ -114,24 +620,28 @@ impl<T, I: IntoIterator<Item = T>> FromIterator<I> for Grid<T> {
         let mut grid = vec![];
 
         for iiter in iter {
-            height += 1;
-            width = 0;
+            let start = grid.len();
+            grid.extend(iiter);
 
-            for item in iiter {
-                width += 1;
-                grid.push(item);
-            }
+            let row_width = grid.len() - start;
+            assert!(
+                height == 0 || row_width == width,
+                "row {height} has {row_width} items instead of {width}"
+            );
+
+            width = row_width;
+            height += 1;
         }
 
         Self {
             grid,
-            width: width,
-            height: height,
+            width,
+            height,
         }
     }
 }
 
-impl<T: Display + PartialEq> Display for Grid<T> {
+impl<T: Display> Display for Grid<T> {
     fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error> {
         for y in 0..self.height {
             for x in 0..self.width {
@@ -143,3 +653,256 @@ impl<T: Display + PartialEq> Display for Grid<T> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    struct Digit(u32);
+
+    impl TryFrom<char> for Digit {
+        type Error = ();
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            c.to_digit(10).map(Digit).ok_or(())
+        }
+    }
+
+    #[test]
+    fn checked_apply() {
+        let grid = Grid::new(3, 2, ());
+
+        assert_eq!(Pos::new(0, 0).checked_apply(Dir::N, &grid), None);
+        assert_eq!(Pos::new(0, 0).checked_apply(Dir::W, &grid), None);
+        assert_eq!(Pos::new(2, 1).checked_apply(Dir::E, &grid), None);
+        assert_eq!(Pos::new(2, 1).checked_apply(Dir::S, &grid), None);
+        assert_eq!(
+            Pos::new(1, 1).checked_apply(Dir::N, &grid),
+            Some(Pos::new(1, 0))
+        );
+        assert_eq!(
+            Pos::new(1, 1).checked_apply(Dir::E, &grid),
+            Some(Pos::new(2, 1))
+        );
+    }
+
+    #[test]
+    fn wrapping_apply() {
+        let grid = Grid::new(3, 2, ());
+
+        assert_eq!(
+            Pos::new(0, 0).wrapping_apply(Dir::N, &grid),
+            Some(Pos::new(0, 1))
+        );
+        assert_eq!(
+            Pos::new(0, 0).wrapping_apply(Dir::W, &grid),
+            Some(Pos::new(2, 0))
+        );
+        assert_eq!(
+            Pos::new(2, 1).wrapping_apply(Dir::E, &grid),
+            Some(Pos::new(0, 1))
+        );
+        assert_eq!(
+            Pos::new(2, 1).wrapping_apply(Dir::S, &grid),
+            Some(Pos::new(2, 0))
+        );
+
+        for grid in [
+            Grid::new(0, 0, ()),
+            Grid::new(3, 0, ()),
+            Grid::new(0, 2, ()),
+        ] {
+            for dir in Dir::ALL {
+                assert_eq!(Pos::new(0, 0).wrapping_apply(dir, &grid), None);
+            }
+        }
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::new(3, 2, ());
+
+        assert_eq!(
+            grid.neighbors(Pos::new(0, 0)).collect::<Vec<_>>(),
+            [(Dir::E, Pos::new(1, 0)), (Dir::S, Pos::new(0, 1))]
+        );
+        assert_eq!(grid.neighbors(Pos::new(1, 1)).count(), 3);
+        assert_eq!(grid.neighbors_8(Pos::new(1, 1)).count(), 5);
+        assert_eq!(
+            grid.neighbors_8(Pos::new(2, 0)).collect::<Vec<_>>(),
+            [
+                ((0, 1), Pos::new(2, 1)),
+                ((-1, 1), Pos::new(1, 1)),
+                ((-1, 0), Pos::new(1, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn neighbors_with() {
+        let grid = Grid::new(5, 5, ());
+        let knight = [(1, 2), (2, 1), (2, -1), (1, -2)];
+
+        assert_eq!(
+            grid.neighbors_with(Pos::new(3, 3), &knight)
+                .map(|(_, pos)| pos)
+                .collect::<Vec<_>>(),
+            [Pos::new(4, 1)]
+        );
+        assert_eq!(grid.neighbors_with(Pos::new(0, 2), &NEIGHBORS_4).count(), 3);
+    }
+
+    #[test]
+    fn iterators() {
+        let mut grid = Grid::from_iter([[1, 2, 3], [4, 5, 6]]);
+
+        assert_eq!(grid.iter().sum::<i32>(), 21);
+        assert_eq!(grid.positions().nth(4), Some(Pos::new(1, 1)));
+        assert_eq!(grid.enumerate().nth(2), Some((Pos::new(2, 0), &3)));
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.copied().collect())
+                .collect::<Vec<Vec<_>>>(),
+            [[1, 4], [2, 5], [3, 6]]
+        );
+        assert_eq!(grid.find(|&item| item > 4), Some(Pos::new(1, 1)));
+        assert_eq!(grid.find(|&item| item > 6), None);
+
+        grid.iter_mut().for_each(|item| *item *= 2);
+        assert_eq!(*grid.get(2, 1), 12);
+    }
+
+    #[test]
+    fn transformations() {
+        let grid = Grid::from_iter([[1, 2, 3], [4, 5, 6]]);
+
+        assert_eq!(grid.transpose(), Grid::from_iter([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(grid.rotate_cw(), Grid::from_iter([[4, 1], [5, 2], [6, 3]]));
+        assert_eq!(grid.rotate_ccw(), Grid::from_iter([[3, 6], [2, 5], [1, 4]]));
+        assert_eq!(
+            grid.flip_horizontal(),
+            Grid::from_iter([[3, 2, 1], [6, 5, 4]])
+        );
+        assert_eq!(
+            grid.flip_vertical(),
+            Grid::from_iter([[4, 5, 6], [1, 2, 3]])
+        );
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+
+    #[test]
+    fn view() {
+        let grid = Grid::from_iter([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let view = grid.view(Pos::new(1, 1), 2, 2);
+
+        assert_eq!((view.width(), view.height()), (2, 2));
+        assert_eq!(*view.get(1, 0), 6);
+        assert_eq!(view.rows().collect::<Vec<_>>(), [[5, 6], [8, 9]]);
+        assert_eq!(view.enumerate().nth(2), Some((Pos::new(0, 1), &8)));
+        assert_eq!(view.to_grid(), Grid::from_iter([[5, 6], [8, 9]]));
+    }
+
+    #[test]
+    fn flood_fill() {
+        let grid = Grid::from_iter([[1, 1, 0], [0, 1, 0], [1, 1, 0], [0, 0, 1]]);
+
+        let region = grid.flood_fill(Pos::new(0, 0), |&cell| cell == 1).unwrap();
+        assert_eq!(region.size(), 5);
+        assert_eq!(region.bounding_box(), (Pos::new(0, 0), Pos::new(1, 2)));
+        assert!(region.positions().contains(&Pos::new(0, 2)));
+
+        assert_eq!(grid.flood_fill(Pos::new(2, 0), |&cell| cell == 1), None);
+    }
+
+    #[test]
+    fn components() {
+        let grid = Grid::from_iter([[1, 1, 0], [0, 1, 0], [1, 1, 0], [0, 0, 1]]);
+        let components = grid.components(|&cell| cell == 0);
+
+        let sizes: Vec<_> = components.regions().iter().map(Region::size).collect();
+        assert_eq!(sizes, [3, 1, 2]);
+        assert_eq!(components.label(Pos::new(2, 2)), Some(0));
+        assert_eq!(components.label(Pos::new(1, 3)), Some(2));
+        assert_eq!(components.label(Pos::new(2, 3)), None);
+        assert_eq!(
+            components.regions()[2].bounding_box(),
+            (Pos::new(0, 3), Pos::new(1, 3))
+        );
+    }
+
+    #[test]
+    fn ipos() {
+        let pos = IPos::new(1, 2).apply(Dir::N, 5).apply(Dir::W, 3);
+
+        assert_eq!(pos, IPos::new(-2, -3));
+        assert_eq!(pos.manhattan_distance(IPos::default()), 5);
+        assert!(Pos::try_from(pos).is_err());
+        assert_eq!(Pos::try_from(IPos::new(4, 0)), Ok(Pos::new(4, 0)));
+        assert_eq!(IPos::try_from(Pos::new(4, 0)), Ok(IPos::new(4, 0)));
+    }
+
+    #[test]
+    fn parse() {
+        let grid = Grid::from_iter([[Digit(1), Digit(2)], [Digit(3), Digit(4)]]);
+
+        assert_eq!(Grid::parse("12\n34"), Ok(grid.clone()));
+        assert_eq!(Grid::parse("12\n34\n"), Ok(grid.clone()));
+        assert_eq!(Grid::parse("12\n34\n\n\n"), Ok(grid.clone()));
+        assert_eq!(Grid::parse("12\r\n34\r\n\r\n"), Ok(grid));
+        assert_eq!(Grid::<Digit>::parse("").map(|grid| grid.width()), Ok(0));
+    }
+
+    #[test]
+    fn parse_ragged() {
+        let input = "12\n345\n";
+        let error = Grid::<Digit>::parse(input).unwrap_err().locate(input);
+        assert_eq!((error.line(), error.column()), (Some(2), Some(3)));
+        assert_eq!(error.text(), "5");
+
+        let input = "12\r\n3\r\n";
+        let error = Grid::<Digit>::parse(input).unwrap_err().locate(input);
+        assert_eq!((error.line(), error.column()), (Some(2), Some(2)));
+    }
+
+    #[test]
+    fn try_parse_with() {
+        let grid = Grid::try_parse_with("#.\n.#", |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(c),
+        });
+        assert_eq!(grid, Ok(Grid::from_iter([[true, false], [false, true]])));
+
+        let input = "#.\n.x";
+        let error = Grid::try_parse_with(input, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(c),
+        })
+        .unwrap_err()
+        .locate(input);
+        assert_eq!((error.line(), error.column()), (Some(2), Some(2)));
+        assert_eq!(error.reason(), "unexpected character");
+    }
+
+    #[test]
+    fn parse_section() {
+        // Errors in a grid that is parsed from a later section are located in the whole input.
+        let input = "#.\n.#\n\n#.\n.#\n#x\n";
+        let error = parse::Sections::new(input)
+            .repeat::<_, Vec<_>>("a pattern", |section| {
+                Grid::try_parse_with(section, |c| match c {
+                    '#' => Ok(true),
+                    '.' => Ok(false),
+                    _ => Err(c),
+                })
+            })
+            .unwrap_err()
+            .locate(input);
+
+        assert_eq!((error.line(), error.column()), (Some(6), Some(2)));
+    }
+}
@@ -0,0 +1,236 @@
+use super::Pos;
+use crate::Dir;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A grid of booleans packed into bits, for the visited and energized sets of searches and
+/// simulations. Unlike a `Grid<bool>`, it takes one bit per cell and can be cleared, counted
+/// and combined a word at a time.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// Creates a grid with no bits set.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            words: vec![0; (width * height).div_ceil(WORD_BITS)],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, pos: Pos) -> (usize, u64) {
+        assert!(
+            pos.x < self.width && pos.y < self.height,
+            "{pos:?} is outside of a {}x{} grid",
+            self.width,
+            self.height
+        );
+
+        let index = pos.y * self.width + pos.x;
+        (index / WORD_BITS, 1 << (index % WORD_BITS))
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        let (word, mask) = self.index(pos);
+        self.words[word] & mask != 0
+    }
+
+    /// Sets the bit at `pos`, returning whether it was not set before.
+    pub fn insert(&mut self, pos: Pos) -> bool {
+        let (word, mask) = self.index(pos);
+        let inserted = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        inserted
+    }
+
+    /// Clears the bit at `pos`, returning whether it was set before.
+    pub fn remove(&mut self, pos: Pos) -> bool {
+        let (word, mask) = self.index(pos);
+        let removed = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        removed
+    }
+
+    /// Clears all bits, keeping the allocation.
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// The positions of the bits that are set, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(|(index, &word)| {
+                let mut word = word;
+                std::iter::from_fn(move || {
+                    (word != 0).then(|| {
+                        let bit = word.trailing_zeros() as usize;
+                        word &= word - 1;
+                        index * WORD_BITS + bit
+                    })
+                })
+            })
+            .map(|index| Pos::new(index % self.width, index / self.width))
+    }
+
+    /// Sets every bit that is set in `other`.
+    pub fn union_with(&mut self, other: &Self) {
+        self.combine(other, |word, other| word | other);
+    }
+
+    /// Clears every bit that is not set in `other`.
+    pub fn intersect_with(&mut self, other: &Self) {
+        self.combine(other, |word, other| word & other);
+    }
+
+    fn combine(&mut self, other: &Self, f: impl Fn(u64, u64) -> u64) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "cannot combine grids of different sizes"
+        );
+
+        for (word, &other) in self.words.iter_mut().zip(&other.words) {
+            *word = f(*word, other);
+        }
+    }
+}
+
+/// A [`BitGrid`] with a bit per direction in each cell, to record which way a cell has been
+/// entered or left. This is enough to detect cycles in walks whose state is a position and a
+/// direction.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DirGrid {
+    bits: BitGrid,
+}
+
+impl DirGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            bits: BitGrid::new(width * 4, height),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.bits.width() / 4
+    }
+
+    pub fn height(&self) -> usize {
+        self.bits.height()
+    }
+
+    fn pos(pos: Pos, dir: Dir) -> Pos {
+        Pos::new(pos.x * 4 + dir as usize, pos.y)
+    }
+
+    pub fn contains(&self, pos: Pos, dir: Dir) -> bool {
+        self.bits.contains(Self::pos(pos, dir))
+    }
+
+    /// Sets the bit for `dir` at `pos`, returning whether it was not set before.
+    pub fn insert(&mut self, pos: Pos, dir: Dir) -> bool {
+        self.bits.insert(Self::pos(pos, dir))
+    }
+
+    /// Whether the bit for any direction is set at `pos`.
+    pub fn contains_any(&self, pos: Pos) -> bool {
+        Dir::ALL.into_iter().any(|dir| self.contains(pos, dir))
+    }
+
+    /// Clears all bits, keeping the allocation.
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    /// The cells that have the bit for any direction set.
+    pub fn cells(&self) -> BitGrid {
+        let mut cells = BitGrid::new(self.width(), self.height());
+
+        for pos in self.bits.iter() {
+            cells.insert(Pos::new(pos.x / 4, pos.y));
+        }
+
+        cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_grid() {
+        let mut grid = BitGrid::new(9, 9);
+
+        assert!(grid.insert(Pos::new(8, 7)));
+        assert!(!grid.insert(Pos::new(8, 7)));
+        assert!(grid.insert(Pos::new(0, 0)));
+        assert!(grid.contains(Pos::new(8, 7)));
+        assert!(!grid.contains(Pos::new(7, 8)));
+        assert_eq!(grid.count_ones(), 2);
+        assert_eq!(
+            grid.iter().collect::<Vec<_>>(),
+            [Pos::new(0, 0), Pos::new(8, 7)]
+        );
+
+        assert!(grid.remove(Pos::new(0, 0)));
+        assert!(!grid.remove(Pos::new(0, 0)));
+        assert_eq!(grid.count_ones(), 1);
+
+        grid.clear();
+        assert_eq!(grid.count_ones(), 0);
+    }
+
+    #[test]
+    fn union_and_intersection() {
+        let mut a = BitGrid::new(10, 10);
+        let mut b = BitGrid::new(10, 10);
+        a.insert(Pos::new(1, 1));
+        a.insert(Pos::new(9, 9));
+        b.insert(Pos::new(9, 9));
+        b.insert(Pos::new(5, 5));
+
+        let mut union = a.clone();
+        union.union_with(&b);
+        assert_eq!(union.count_ones(), 3);
+
+        a.intersect_with(&b);
+        assert_eq!(a.iter().collect::<Vec<_>>(), [Pos::new(9, 9)]);
+    }
+
+    #[test]
+    fn dir_grid() {
+        let mut grid = DirGrid::new(3, 2);
+
+        assert!(grid.insert(Pos::new(2, 1), Dir::W));
+        assert!(!grid.insert(Pos::new(2, 1), Dir::W));
+        assert!(grid.insert(Pos::new(2, 1), Dir::N));
+        assert!(grid.insert(Pos::new(0, 1), Dir::E));
+        assert!(grid.contains(Pos::new(2, 1), Dir::N));
+        assert!(!grid.contains(Pos::new(2, 1), Dir::S));
+        assert!(grid.contains_any(Pos::new(0, 1)));
+        assert!(!grid.contains_any(Pos::new(1, 1)));
+        assert_eq!(grid.cells().count_ones(), 2);
+    }
+}
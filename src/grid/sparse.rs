@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+
+use super::{Grid, IPos};
+use crate::Dir;
+
+/// A grid on an unbounded plane that only stores the cells that have been set, for coordinate
+/// spaces that are too large or irregular for a [`Grid`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<IPos, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, pos: IPos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: IPos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    /// Sets the cell at `pos`, returning its previous value.
+    pub fn insert(&mut self, pos: IPos, value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: IPos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    pub fn contains(&self, pos: IPos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// The number of cells that are set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The cells that are set, with their positions, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (IPos, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    /// The top-left and bottom-right corners of the smallest rectangle containing all cells
+    /// that are set, or `None` if there are none.
+    pub fn bounds(&self) -> Option<(IPos, IPos)> {
+        self.cells.keys().fold(None, |bounds, &pos| {
+            let (min, max) = bounds.unwrap_or((pos, pos));
+            Some((
+                IPos::new(min.x.min(pos.x), min.y.min(pos.y)),
+                IPos::new(max.x.max(pos.x), max.y.max(pos.y)),
+            ))
+        })
+    }
+
+    /// The orthogonal neighbors of `pos`, with their direction.
+    pub fn neighbors(&self, pos: IPos) -> impl Iterator<Item = (Dir, IPos)> {
+        pos.neighbors()
+    }
+
+    /// The orthogonal and diagonal neighbors of `pos`, with their offset from `pos`.
+    pub fn neighbors_8(&self, pos: IPos) -> impl Iterator<Item = ((isize, isize), IPos)> {
+        pos.neighbors_8()
+    }
+
+    /// The positions at the offsets in `stencil` from `pos`, with their offset.
+    pub fn neighbors_with<'a>(
+        &self,
+        pos: IPos,
+        stencil: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = ((isize, isize), IPos)> + 'a {
+        pos.neighbors_with(stencil)
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Copies the cells within [`SparseGrid::bounds`] into a dense grid, filling the cells that
+    /// are not set with `default`. The top-left corner of the bounds becomes `(0, 0)`.
+    pub fn to_grid(&self, default: T) -> Grid<T> {
+        let Some((min, max)) = self.bounds() else {
+            return Grid::from_fn(0, 0, |_| default.clone());
+        };
+
+        Grid::from_fn(
+            max.x.abs_diff(min.x) + 1,
+            max.y.abs_diff(min.y) + 1,
+            |pos| {
+                let pos = IPos::new(min.x + pos.x as isize, min.y + pos.y as isize);
+                self.get(pos).unwrap_or(&default).clone()
+            },
+        )
+    }
+}
+
+impl<T> FromIterator<(IPos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (IPos, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparse_grid() {
+        let mut grid = SparseGrid::new();
+        grid.insert(IPos::new(-1_000_000, 3), 'a');
+        grid.insert(IPos::new(1_000_000, -2), 'b');
+
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.get(IPos::new(1_000_000, -2)), Some(&'b'));
+        assert_eq!(grid.get(IPos::new(0, 0)), None);
+        assert_eq!(
+            grid.bounds(),
+            Some((IPos::new(-1_000_000, -2), IPos::new(1_000_000, 3)))
+        );
+        assert_eq!(
+            grid.neighbors(IPos::new(0, 0)).next(),
+            Some((Dir::N, IPos::new(0, -1)))
+        );
+        assert_eq!(grid.neighbors_8(IPos::new(0, 0)).count(), 8);
+    }
+
+    #[test]
+    fn to_grid() {
+        let grid: SparseGrid<_> = [(IPos::new(-1, 0), 1), (IPos::new(1, 1), 2)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(grid.to_grid(0), Grid::from_iter([[1, 0, 0], [0, 0, 2]]));
+    }
+}
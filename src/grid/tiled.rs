@@ -0,0 +1,103 @@
+use super::{Grid, IPos, Pos};
+use crate::Dir;
+
+/// A view that repeats a non-empty [`Grid`] infinitely in every direction, as created by
+/// [`Grid::tiled`]. The tile at `(0, 0)` has the same coordinates as the grid itself.
+#[derive(Clone, Copy, Debug)]
+pub struct TiledGrid<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T> TiledGrid<'a, T> {
+    /// Repeats `grid`, or returns `None` if it is empty.
+    pub(super) fn new(grid: &'a Grid<T>) -> Option<Self> {
+        (grid.width() > 0 && grid.height() > 0).then_some(Self { grid })
+    }
+
+    pub fn get(&self, pos: IPos) -> &'a T {
+        let Pos { x, y } = self.wrap(pos);
+        self.grid.get(x, y)
+    }
+
+    /// The position in the underlying grid that `pos` corresponds to.
+    pub fn wrap(&self, pos: IPos) -> Pos {
+        Pos::new(
+            pos.x.rem_euclid(self.grid.width() as isize) as usize,
+            pos.y.rem_euclid(self.grid.height() as isize) as usize,
+        )
+    }
+
+    /// The coordinates of the copy of the grid that contains `pos`, where `(0, 0)` is the
+    /// original.
+    pub fn tile(&self, pos: IPos) -> IPos {
+        IPos::new(
+            pos.x.div_euclid(self.grid.width() as isize),
+            pos.y.div_euclid(self.grid.height() as isize),
+        )
+    }
+
+    /// The orthogonal neighbors of `pos`, with their direction.
+    pub fn neighbors(&self, pos: IPos) -> impl Iterator<Item = (Dir, IPos)> {
+        pos.neighbors()
+    }
+
+    /// The orthogonal and diagonal neighbors of `pos`, with their offset from `pos`.
+    pub fn neighbors_8(&self, pos: IPos) -> impl Iterator<Item = ((isize, isize), IPos)> {
+        pos.neighbors_8()
+    }
+
+    /// The positions at the offsets in `stencil` from `pos`, with their offset.
+    pub fn neighbors_with<'b>(
+        &self,
+        pos: IPos,
+        stencil: &'b [(isize, isize)],
+    ) -> impl Iterator<Item = ((isize, isize), IPos)> + 'b {
+        pos.neighbors_with(stencil)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search;
+
+    #[test]
+    fn tiled_grid() {
+        let grid = Grid::from_iter([[1, 2, 3], [4, 5, 6]]);
+        let tiled = grid.tiled().unwrap();
+
+        assert_eq!(*tiled.get(IPos::new(0, 0)), 1);
+        assert_eq!(*tiled.get(IPos::new(-1, 0)), 3);
+        assert_eq!(*tiled.get(IPos::new(4, -1)), 5);
+        assert_eq!(tiled.wrap(IPos::new(-4, 5)), Pos::new(2, 1));
+        assert_eq!(tiled.tile(IPos::new(-4, 5)), IPos::new(-2, 2));
+
+        assert!(Grid::<u8>::from_fn(0, 3, |_| 0).tiled().is_none());
+        assert!(Grid::<u8>::from_fn(3, 0, |_| 0).tiled().is_none());
+    }
+
+    #[test]
+    fn search() {
+        // The wall in the middle column repeats forever, so from the left column the right column
+        // is only reachable in the tile to the west.
+        let grid = Grid::from_iter([[true, false, true]]);
+        let tiled = grid.tiled().unwrap();
+        let goal = IPos::new(-1, 0);
+
+        let search = search::bfs(
+            [IPos::new(0, 0)],
+            |&pos| {
+                tiled
+                    .neighbors(pos)
+                    .map(|(_, next)| next)
+                    .filter(|&next| *tiled.get(next))
+                    .collect::<Vec<_>>()
+            },
+            |&pos| pos == goal,
+        );
+
+        assert_eq!(search.goal(), Some((&goal, 1)));
+        assert_eq!(tiled.wrap(goal), Pos::new(2, 0));
+        assert_eq!(tiled.tile(goal), IPos::new(-1, 0));
+    }
+}
@@ -0,0 +1,88 @@
+//! Just enough JSON to print flat objects, one per line, for `--format json`.
+
+use std::fmt::{self, Display, Formatter};
+
+/// A JSON object whose fields are written in the order in which they are added.
+#[derive(Default)]
+pub(crate) struct Object {
+    fields: Vec<(&'static str, String)>,
+}
+
+impl Object {
+    pub fn number(mut self, key: &'static str, value: impl Into<u128>) -> Self {
+        self.fields.push((key, value.into().to_string()));
+        self
+    }
+
+    pub fn string(mut self, key: &'static str, value: &str) -> Self {
+        self.fields.push((key, escape(value)));
+        self
+    }
+
+    /// Adds a string field, or `null` if there is no value.
+    pub fn optional_string(self, key: &'static str, value: Option<&str>) -> Self {
+        match value {
+            Some(value) => self.string(key, value),
+            None => self.null(key),
+        }
+    }
+
+    pub fn null(mut self, key: &'static str) -> Self {
+        self.fields.push((key, "null".to_string()));
+        self
+    }
+}
+
+impl Display for Object {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(fmt, "{{")?;
+
+        for (index, (key, value)) in self.fields.iter().enumerate() {
+            if index > 0 {
+                write!(fmt, ",")?;
+            }
+            write!(fmt, "{}:{value}", escape(key))?;
+        }
+
+        write!(fmt, "}}")
+    }
+}
+
+/// Quotes `text` as a JSON string.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn object() {
+        let object = Object::default()
+            .number("day", 7u8)
+            .string("answer", "a \"quoted\"\nline\0")
+            .optional_string("error", None);
+
+        assert_eq!(
+            object.to_string(),
+            r#"{"day":7,"answer":"a \"quoted\"\nline\u0000","error":null}"#
+        );
+    }
+}
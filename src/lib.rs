@@ -1,38 +1,40 @@
 //! This library defines a framework for solving Advent of Code puzzles.
-//! Copy-and-paste the code fragment below into a new src/bin/day_<number>.rs file.
+//! Copy-and-paste the code fragment below into a new src/days/day_<number>.rs file and add
+//! the module and its `SOLVER` to the registry in src/days/mod.rs.
 //! Change the types of Input, Output1 and Output2, and fill in the todo!() statements
-//! to solve the puzzles.
+//! and the unimplemented parts to solve the puzzles. Finally, add a src/bin/day_<number>.rs
+//! file that calls `aoc::run_day(<number>)`, or run the puzzle with the `aoc` binary.
 //!
-//! ```no_run
+//! ```ignore
 //! struct Solver;
 //!
-//! impl aoc::Solver for Solver {
+//! pub(super) const SOLVER: crate::Day = crate::Day::new::<Solver>();
+//!
+//! impl crate::Solver for Solver {
+//!     const DAY: u8 = todo!();
+//!
 //!     type Input = Vec<u32>;
 //!     type Output1 = u32;
 //!     type Output2 = u32;
 //!
-//!     fn parse(_input: &str) -> Self::Input {
+//!     fn parse(_input: &str) -> Result<Self::Input, crate::ParseError> {
 //!         todo!()
 //!     }
 //!
-//!     fn part_1(_input: &Self::Input) -> Self::Output1 {
-//!         todo!()
+//!     fn part_1(_input: &Self::Input) -> crate::Answer<Self::Output1> {
+//!         Err(crate::Unsolved::NotImplemented)
 //!     }
 //!
-//!     fn part_2(_input: &Self::Input) -> Self::Output2 {
-//!         todo!()
+//!     fn part_2(_input: &Self::Input) -> crate::Answer<Self::Output2> {
+//!         Err(crate::Unsolved::NotImplemented)
 //!     }
 //! }
 //!
-//! fn main() {
-//!     aoc::run::<Solver>();
-//! }
-//!
 //! #[cfg(test)]
 //! mod tests {
 //!     use super::*;
 //!
-//!     fn get_input() -> <Solver as aoc::Solver>::Input {
+//!     fn get_input() -> <Solver as crate::Solver>::Input {
 //!         todo!()
 //!     }
 //!
@@ -40,83 +42,575 @@
 //!     fn parsing() {
 //!         let input = todo!();
 //!
-//!         assert_eq!(<Solver as aoc::Solver>::parse(input), get_input());
+//!         assert_eq!(<Solver as crate::Solver>::parse(input), Ok(get_input()));
 //!     }
 //!
 //!     #[test]
-//!     #[allow(unreachable_code)]
+//!     #[allow(unreachable_code, clippy::diverging_sub_expression)]
 //!     fn part_1() {
-//!         assert_eq!(<Solver as aoc::Solver>::part_1(&get_input()), todo!());
+//!         assert_eq!(<Solver as crate::Solver>::part_1(&get_input()), Ok(todo!()));
 //!     }
 //!
 //!     #[test]
-//!     #[allow(unreachable_code)]
+//!     #[allow(unreachable_code, clippy::diverging_sub_expression)]
 //!     fn part_2() {
-//!         assert_eq!(<Solver as aoc::Solver>::part_2(&get_input()), todo!());
+//!         assert_eq!(<Solver as crate::Solver>::part_2(&get_input()), Ok(todo!()));
 //!     }
 //! }
 //! ```
 
-use std::fmt::Display;
+use std::fmt::{self, Display, Formatter};
 use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process;
 use std::time::{Duration, Instant};
 
 pub mod dir;
+pub mod graph;
 pub mod grid;
-pub use dir::Dir;
+pub use dir::{Dir, Dir8};
 pub use grid::Grid;
+pub mod parse;
+pub mod search;
+pub use parse::ParseError;
 
 mod args;
-use args::Args;
+use args::{Args, Format, Input, Parts};
+use duration::Units;
+
+mod bench;
+mod duration;
+mod json;
+use bench::Benchmark;
+
+mod days;
 
 pub trait Solver {
+    const DAY: u8;
+
     type Input;
     type Output1: Display;
     type Output2: Display;
 
-    fn parse(input: &str) -> Self::Input;
-    fn part_1(input: &Self::Input) -> Self::Output1;
-    fn part_2(input: &Self::Input) -> Self::Output2;
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_1(input: &Self::Input) -> Answer<Self::Output1>;
+    fn part_2(input: &Self::Input) -> Answer<Self::Output2>;
+}
+
+/// The outcome of solving a part: either the answer, or the reason why there is none.
+pub type Answer<T> = Result<T, Unsolved>;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Unsolved {
+    /// The part has not been solved yet.
+    NotImplemented,
+    /// The input has no answer, for example because a search did not find anything.
+    NoSolution,
+    /// The day has no such puzzle, as the last day only has a first part.
+    NoPuzzle,
+}
+
+impl Unsolved {
+    /// The representation of the outcome in `--quiet` output, where it takes the place of the
+    /// answer. The `!` prefix distinguishes it from actual answers.
+    fn code(&self) -> &'static str {
+        match self {
+            Unsolved::NotImplemented => "!not-implemented",
+            Unsolved::NoSolution => "!no-solution",
+            Unsolved::NoPuzzle => "!no-puzzle",
+        }
+    }
+}
+
+impl Display for Unsolved {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Unsolved::NotImplemented => write!(fmt, "not implemented"),
+            Unsolved::NoSolution => write!(fmt, "no solution"),
+            Unsolved::NoPuzzle => write!(fmt, "no puzzle"),
+        }
+    }
+}
+
+/// A type-erased [`Solver`], so that the solvers of all days can be stored side by side.
+#[derive(Clone, Copy)]
+struct Day {
+    day: u8,
+    solve: fn(&str, Parts) -> Result<Results, ParseError>,
+    bench: fn(&str, Parts, usize) -> Result<Benchmark, ParseError>,
+}
+
+impl Day {
+    const fn new<S: Solver>() -> Self {
+        Self {
+            day: S::DAY,
+            solve: solve::<S>,
+            bench: bench::bench::<S>,
+        }
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn solve(&self, input: &str, parts: Parts) -> Result<Results, ParseError> {
+        (self.solve)(input, parts)
+    }
+
+    fn bench(&self, input: &str, parts: Parts, runs: usize) -> Result<Benchmark, ParseError> {
+        (self.bench)(input, parts, runs)
+    }
+}
+
+struct PartResult {
+    answer: Answer<String>,
+    elapsed: Duration,
+}
+
+impl PartResult {
+    /// The answer, or the outcome if there is none, as printed by `--quiet`.
+    fn quiet(&self) -> &str {
+        match &self.answer {
+            Ok(answer) => answer,
+            Err(unsolved) => unsolved.code(),
+        }
+    }
+
+    /// The outcome as a single word, for structured output.
+    fn status(&self) -> &'static str {
+        match self.answer {
+            Ok(_) => "answered",
+            Err(Unsolved::NotImplemented) => "not-implemented",
+            Err(Unsolved::NoSolution) => "no-solution",
+            Err(Unsolved::NoPuzzle) => "no-puzzle",
+        }
+    }
+
+    /// The answer, or a description of the outcome if there is none.
+    fn human(&self) -> String {
+        match &self.answer {
+            Ok(answer) => answer.clone(),
+            Err(unsolved) => format!("({unsolved})"),
+        }
+    }
+}
+
+struct Results {
+    parse: Duration,
+    part_1: Option<PartResult>,
+    part_2: Option<PartResult>,
+}
+
+impl Results {
+    /// The results of the parts that were run, with their numbers.
+    fn parts(&self) -> impl Iterator<Item = (u8, &PartResult)> {
+        [(1, &self.part_1), (2, &self.part_2)]
+            .into_iter()
+            .filter_map(|(part, result)| Some((part, result.as_ref()?)))
+    }
+
+    /// The time spent parsing and solving all parts that were run.
+    fn elapsed(&self) -> Duration {
+        self.parse
+            + self
+                .parts()
+                .map(|(_, result)| result.elapsed)
+                .sum::<Duration>()
+    }
+}
+
+fn solve<S: Solver>(input: &str, parts: Parts) -> Result<Results, ParseError> {
+    let start = Instant::now();
+    let input = S::parse(input).map_err(|error| error.locate(input))?;
+    let parse = start.elapsed();
+
+    Ok(Results {
+        parse,
+        part_1: parts.run_part_1.then(|| time(|| S::part_1(&input))),
+        part_2: parts.run_part_2.then(|| time(|| S::part_2(&input))),
+    })
+}
+
+fn time<D: Display>(part: impl FnOnce() -> Answer<D>) -> PartResult {
+    let start = Instant::now();
+    let answer = part();
+    let elapsed = start.elapsed();
+
+    PartResult {
+        answer: answer.map(|answer| answer.to_string()),
+        elapsed,
+    }
 }
 
+/// Runs a single solver on the input given on the command line, or on `data/input/<day>` if
+/// none is given.
 pub fn run<S: Solver>() {
-    let args = args::parse();
+    run_single(Day::new::<S>());
+}
+
+/// Runs the registered solver of the given day on the input given on the command line, or on
+/// `data/input/<day>` if none is given.
+pub fn run_day(day: u8) {
+    run_single(days::get(day).unwrap());
+}
+
+fn run_single(day: Day) {
+    let mut args = args::parse();
+    let input = args
+        .input
+        .take()
+        .unwrap_or_else(|| Input::File(input_path(day.day())));
+
+    let file_contents = match read_input(&input) {
+        Ok(contents) => contents,
+        Err(error) => {
+            eprintln!("Cannot read {input}: {error}");
+            process::exit(1);
+        }
+    };
+    if let Some(runs) = args.bench {
+        match day.bench(&file_contents, args.parts, runs) {
+            Ok(benchmark) => bench::print(&benchmark, args.units),
+            Err(error) => {
+                eprintln!("Invalid input in {input}: {error}");
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
+    let results = match day.solve(&file_contents, args.parts) {
+        Ok(results) => results,
+        Err(error) => {
+            eprintln!("Invalid input in {input}: {error}");
+            process::exit(1);
+        }
+    };
+
+    if args.quiet {
+        // The answers on stdout stay machine-readable, so the timings go to stderr instead.
+        eprint!("parse={}\0", args.units.format(results.parse));
+    } else if args.format == Format::Human {
+        println!("Parsed input in {}", args.units.format(results.parse));
+        println!();
+    }
+
+    let expected = args.check.then(|| read_expected(day.day()));
+    let mut passed = true;
+
+    for (part, result) in results.parts() {
+        let check = expected.as_ref().map(|expected| {
+            let expected = expected.get(usize::from(part) - 1).map(String::as_str);
+            Check::new(result, expected)
+        });
+        passed &= !matches!(check, Some(Check::Fail(_)));
+
+        match (args.format, &check) {
+            (Format::Json, _) => println!(
+                "{}",
+                json_result(day.day(), part, &results, result, check.as_ref())
+            ),
+            (Format::Human, Some(check)) => print_check(result, part, check, &args),
+            (Format::Human, None) => print_result(result, part, &args),
+        }
+    }
+
+    if args.quiet {
+        eprintln!("total={}\0", args.units.format(results.elapsed()));
+    } else if args.format == Format::Human {
+        println!("Total time: {}", args.units.format(results.elapsed()));
+    }
+
+    if !passed {
+        process::exit(1);
+    }
+}
+
+/// The outcome of comparing the answer of a part with the expected answer.
+enum Check<'a> {
+    Pass,
+    Fail(&'a str),
+    Unknown,
+}
 
-    let file_contents = fs::read_to_string(&args.path).unwrap();
-    let input = S::parse(&file_contents);
+impl<'a> Check<'a> {
+    fn new(result: &PartResult, expected: Option<&'a str>) -> Self {
+        match expected {
+            Some(expected) if expected == result.quiet() => Check::Pass,
+            Some(expected) => Check::Fail(expected),
+            None => Check::Unknown,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Check::Pass => "PASS",
+            Check::Fail(_) => "FAIL",
+            Check::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+/// The default location of the input of a day.
+fn input_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("data/input/{day}"))
+}
+
+/// Reads the expected answers of a day from `data/output/<day>`, in the same NUL-separated
+/// format that `--quiet` prints. A missing file means that no answers are known yet.
+fn read_expected(day: u8) -> Vec<String> {
+    fs::read_to_string(format!("data/output/{day}"))
+        .map(|contents| {
+            contents
+                .trim()
+                .split_terminator('\0')
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
 
-    if args.run_part_1 {
-        let start = Instant::now();
-        let part_1 = S::part_1(&input);
-        print_result(part_1, 1, &args, start.elapsed());
+fn read_input(input: &Input) -> io::Result<String> {
+    match input {
+        Input::Stdin => {
+            let mut contents = String::new();
+            io::stdin().read_to_string(&mut contents)?;
+            Ok(contents)
+        }
+        Input::File(path) => fs::read_to_string(path),
     }
+}
+
+/// Prints whether the answer of a part matches the expected answer.
+fn print_check(result: &PartResult, part: u8, check: &Check, args: &Args) {
+    let actual = result.quiet();
+
+    println!(
+        "Part {part}: {} (solved in {})",
+        check.name(),
+        args.units.format(result.elapsed)
+    );
+
+    match check {
+        Check::Fail(expected) => {
+            println!("expected: {expected}");
+            println!("actual:   {actual}");
+        }
+        Check::Pass | Check::Unknown => println!("{actual}"),
+    }
+    println!();
+}
+
+/// The result of a part as a JSON object. Durations are in nanoseconds, and answers are
+/// strings, as they do not all fit in a JSON number.
+fn json_result(
+    day: u8,
+    part: u8,
+    results: &Results,
+    result: &PartResult,
+    check: Option<&Check>,
+) -> json::Object {
+    let object = json::Object::default()
+        .number("day", day)
+        .number("part", part)
+        .string("status", result.status())
+        .optional_string("answer", result.answer.as_deref().ok())
+        .number("parse_ns", results.parse.as_nanos())
+        .number("solve_ns", result.elapsed.as_nanos())
+        .number("total_ns", results.elapsed().as_nanos());
 
-    if args.run_part_2 {
-        let start = Instant::now();
-        let part_2 = S::part_2(&input);
-        print_result(part_2, 2, &args, start.elapsed());
+    match check {
+        Some(check) => object
+            .string("check", &check.name().to_lowercase())
+            .optional_string(
+                "expected",
+                match check {
+                    Check::Fail(expected) => Some(expected),
+                    Check::Pass => result.answer.as_deref().ok(),
+                    Check::Unknown => None,
+                },
+            ),
+        None => object,
     }
 }
 
-fn print_result<D: Display>(result: D, part: u8, args: &Args, elapsed: Duration) {
+fn print_result(result: &PartResult, part: u8, args: &Args) {
     if args.quiet {
-        print!("{result}\0");
+        print!("{}\0", result.quiet());
+        eprint!("part_{part}={}\0", args.units.format(result.elapsed));
     } else {
-        println!(
-            "Result of part {part} (solved in {}):",
-            duration_to_string(elapsed)
-        );
-        println!("{result}");
+        match &result.answer {
+            Ok(answer) => {
+                println!(
+                    "Result of part {part} (solved in {}):",
+                    args.units.format(result.elapsed)
+                );
+                println!("{answer}");
+            }
+            Err(unsolved) => println!("Part {part}: {unsolved}"),
+        }
         println!();
     }
 }
 
-fn duration_to_string(duration: Duration) -> String {
-    format!(
-        "{}.{:0>3} {:0>3} {:0>3} s",
-        duration.as_secs(),
-        duration.as_millis(),
-        duration.as_micros() % 1_000,
-        duration.as_nanos() % 1_000,
-    )
+/// Runs the registered solvers for the days given on the command line (or all days if none are
+/// given), reading the input of each day from `data/input/<day>`, and prints a summary table.
+pub fn run_season() {
+    let args = args::parse_season();
+
+    let rows: Vec<_> = days::DAYS
+        .iter()
+        .filter(|day| args.days.contains(&day.day()))
+        .map(|day| {
+            let path = input_path(day.day());
+            let results = match fs::read_to_string(&path) {
+                Ok(input) => day.solve(&input, args.parts).map_err(Failure::InvalidInput),
+                Err(_) => Err(Failure::MissingInput(path)),
+            };
+            (day.day(), results)
+        })
+        .collect();
+
+    // A missing input only means that the puzzle has not been downloaded yet, but an invalid
+    // input means that a solver is broken.
+    let invalid = rows
+        .iter()
+        .any(|(_, results)| matches!(results, Err(Failure::InvalidInput(_))));
+
+    if args.quiet {
+        for (day, results) in &rows {
+            print!("{day}\0");
+            if let Ok(results) = results {
+                eprint!("{day}\0parse={}\0", args.units.format(results.parse));
+                for (part, result) in results.parts() {
+                    print!("{}\0", result.quiet());
+                    eprint!("part_{part}={}\0", args.units.format(result.elapsed));
+                }
+                eprintln!("total={}\0", args.units.format(results.elapsed()));
+            }
+            println!();
+        }
+    } else if args.format == Format::Json {
+        for (day, results) in &rows {
+            match results {
+                Ok(results) => {
+                    for (part, result) in results.parts() {
+                        println!("{}", json_result(*day, part, results, result, None));
+                    }
+                }
+                Err(failure) => println!(
+                    "{}",
+                    json::Object::default()
+                        .number("day", *day)
+                        .string("status", failure.status())
+                        .string("error", &failure.to_string())
+                ),
+            }
+        }
+    } else {
+        print_table(&rows, args.units);
+    }
+
+    if invalid {
+        process::exit(1);
+    }
+}
+
+enum Failure {
+    MissingInput(PathBuf),
+    InvalidInput(ParseError),
+}
+
+impl Failure {
+    /// The failure as a single word, for structured output.
+    fn status(&self) -> &'static str {
+        match self {
+            Failure::MissingInput(_) => "missing-input",
+            Failure::InvalidInput(_) => "invalid-input",
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Failure::MissingInput(path) => write!(fmt, "no input at {}", path.display()),
+            Failure::InvalidInput(error) => write!(fmt, "invalid input: {error}"),
+        }
+    }
+}
+
+fn print_table(rows: &[(u8, Result<Results, Failure>)], units: Units) {
+    let mut table = vec![vec![
+        "Day".to_string(),
+        "Parse".to_string(),
+        "Part 1".to_string(),
+        "Time".to_string(),
+        "Part 2".to_string(),
+        "Time".to_string(),
+    ]];
+
+    let mut total = Duration::ZERO;
+
+    for (day, results) in rows {
+        let mut row = vec![day.to_string()];
+
+        match results {
+            Ok(results) => {
+                row.push(units.format(results.parse));
+                for result in [&results.part_1, &results.part_2] {
+                    match result {
+                        Some(result) => {
+                            row.push(result.human());
+                            row.push(units.format(result.elapsed));
+                        }
+                        None => row.extend(["-".to_string(), "-".to_string()]),
+                    }
+                }
+                total += results.elapsed();
+            }
+            Err(failure) => row.extend([
+                failure.to_string(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+            ]),
+        }
+
+        table.push(row);
+    }
+
+    print_rows(&table);
+
+    println!();
+    println!("Total time: {}", units.format(total));
+}
+
+/// Prints `table` with aligned columns, and a rule below the header in the first row.
+fn print_rows(table: &[Vec<String>]) {
+    let widths: Vec<_> = (0..table[0].len())
+        .map(|column| {
+            table
+                .iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap()
+        })
+        .collect();
+
+    for (index, row) in table.iter().enumerate() {
+        let cells: Vec<_> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        println!("{}", cells.join(" | ").trim_end());
+
+        if index == 0 {
+            let rules: Vec<_> = widths.iter().map(|width| "-".repeat(*width)).collect();
+            println!("{}", rules.join("-+-"));
+        }
+    }
 }
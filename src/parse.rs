@@ -0,0 +1,562 @@
+//! Helpers to parse puzzle inputs without panicking on malformed input.
+//!
+//! Errors are created from the slice of the input that could not be parsed. The runner later
+//! calls [`ParseError::locate`] with the complete input, which turns the address of that slice
+//! into a line and column. Solvers therefore only have to pass along the offending slice.
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    text: String,
+    reason: String,
+    address: usize,
+    position: Option<(usize, usize)>,
+}
+
+impl ParseError {
+    /// Creates an error for `text`, which must be a slice of the input for it to be locatable.
+    pub fn new(text: &str, reason: impl Into<String>) -> Self {
+        Self {
+            text: text.to_string(),
+            reason: reason.into(),
+            address: text.as_ptr() as usize,
+            position: None,
+        }
+    }
+
+    /// Determines the line and column of the offending text, if it is a slice of `input`.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+
+        if self.position.is_none() && (start..=start + input.len()).contains(&self.address) {
+            let before = &input[..self.address - start];
+            let line = before.matches('\n').count() + 1;
+            let column = before
+                .rsplit_once('\n')
+                .map_or(before, |(_, last)| last)
+                .chars()
+                .count()
+                + 1;
+
+            self.position = Some((line, column));
+        }
+
+        self
+    }
+
+    /// The 1-based line of the offending text, if known.
+    pub fn line(&self) -> Option<usize> {
+        self.position.map(|(line, _)| line)
+    }
+
+    /// The 1-based column of the offending text, if known.
+    pub fn column(&self) -> Option<usize> {
+        self.position.map(|(_, column)| column)
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// Prefixes the reason with `context`, such as the part of the input that was being parsed.
+    pub fn context(mut self, context: impl Display) -> Self {
+        self.reason = format!("{context}: {}", self.reason);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error> {
+        if let Some((line, column)) = self.position {
+            write!(fmt, "line {line}, column {column}: ")?;
+        }
+
+        write!(fmt, "{}", self.reason)?;
+
+        if self.text.is_empty() {
+            write!(fmt, " (found nothing)")
+        } else {
+            write!(fmt, " (found {:?})", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `text` as a number, or any other type implementing [`FromStr`].
+pub fn number<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    T::from_str(text).map_err(|_| ParseError::new(text, "expected a number"))
+}
+
+/// Splits `text` at the first occurrence of `delimiter`.
+pub fn split_once<'a>(text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(text, format!("expected {delimiter:?}")))
+}
+
+/// Removes `prefix` from the start of `text`.
+pub fn strip_prefix<'a>(text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(text, format!("expected {prefix:?}")))
+}
+
+/// Removes `suffix` from the end of `text`.
+pub fn strip_suffix<'a>(text: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+    text.strip_suffix(suffix)
+        .ok_or_else(|| ParseError::new(text, format!("expected {suffix:?}")))
+}
+
+/// Returns the next item of `iter`, or an error mentioning `what` if there is none.
+///
+/// `text` is the text that `iter` iterates over, and is reported when the item is missing.
+pub fn next<'a>(
+    iter: &mut impl Iterator<Item = &'a str>,
+    text: &'a str,
+    what: &str,
+) -> Result<&'a str, ParseError> {
+    iter.next()
+        .ok_or_else(|| ParseError::new(&text[text.len()..], format!("expected {what}")))
+}
+
+/// Converts `text`, which must consist of a single character.
+pub fn char<T: TryFrom<char>>(text: &str) -> Result<T, ParseError> {
+    let mut chars = text.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => {
+            T::try_from(c).map_err(|_| ParseError::new(text, "unexpected character"))
+        }
+        _ => Err(ParseError::new(text, "expected a single character")),
+    }
+}
+
+/// Converts every character of `text`, reporting the first one that cannot be converted.
+pub fn chars<T: TryFrom<char>, B: FromIterator<T>>(text: &str) -> Result<B, ParseError> {
+    text.char_indices()
+        .map(|(i, c)| {
+            T::try_from(c)
+                .map_err(|_| ParseError::new(&text[i..i + c.len_utf8()], "unexpected character"))
+        })
+        .collect()
+}
+
+/// Parses the whitespace-separated numbers in `text`, reporting the first token that is not a
+/// number. `"41 48 83"` yields `[41, 48, 83]`.
+pub fn numbers<T: FromStr>(text: &str) -> Result<Vec<T>, ParseError> {
+    text.split_whitespace().map(number).collect()
+}
+
+/// Parses every integer in `text`, with an optional leading minus sign, ignoring the text
+/// around them. `"x=-3, y=12"` yields `[-3, 12]`. Use [`numbers`] for input that should consist
+/// of numbers only.
+pub fn integers<T: FromStr>(text: &str) -> Result<Vec<T>, ParseError> {
+    let bytes = text.as_bytes();
+    let mut integers = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let is_negative = bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+
+        if is_negative || bytes[i].is_ascii_digit() {
+            let start = i;
+            i += 1;
+            while bytes.get(i).is_some_and(u8::is_ascii_digit) {
+                i += 1;
+            }
+
+            let integer = &text[start..i];
+            integers.push(
+                integer
+                    .parse()
+                    .map_err(|_| ParseError::new(integer, "number out of range"))?,
+            );
+        } else {
+            i += 1;
+        }
+    }
+
+    Ok(integers)
+}
+
+/// Splits `text` into the sections that are separated by blank lines. The sections do not
+/// include their trailing line ending, and empty sections are skipped.
+pub fn sections(text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = text;
+    std::iter::from_fn(move || next_section(&mut rest))
+}
+
+/// Removes the next section from the start of `rest` and returns it.
+fn next_section<'a>(rest: &mut &'a str) -> Option<&'a str> {
+    let mut start = None;
+    let mut end = 0;
+
+    for line in rest.split_inclusive('\n') {
+        let is_blank = line.trim().is_empty();
+
+        match start {
+            None if is_blank => {}
+            None => start = Some(end),
+            Some(_) if is_blank => break,
+            Some(_) => {}
+        }
+        end += line.len();
+    }
+
+    let section = &rest[start?..end];
+    *rest = &rest[end..];
+
+    Some(section.trim_end_matches(['\r', '\n']))
+}
+
+/// Parses an input that consists of sections separated by blank lines, one section or group of
+/// sections at a time. Errors mention the number of the section and what it should contain.
+///
+/// ```ignore
+/// let mut sections = parse::Sections::new(input);
+/// let seeds = sections.next("seeds", parse_seeds)?;
+/// let maps: [Map; 7] = sections.array("a map", parse_map)?;
+/// sections.finish()?;
+/// ```
+pub struct Sections<'a> {
+    input: &'a str,
+    rest: &'a str,
+    index: usize,
+}
+
+impl<'a> Sections<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            rest: input,
+            index: 0,
+        }
+    }
+
+    /// Parses the next section with `parser`. `what` describes the section for errors.
+    pub fn next<T>(
+        &mut self,
+        what: &str,
+        parser: impl FnOnce(&'a str) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        self.index += 1;
+        let context = format!("section {} ({what})", self.index);
+
+        let section = next_section(&mut self.rest).ok_or_else(|| {
+            ParseError::new(&self.input[self.input.len()..], format!("expected {what}"))
+                .context(&context)
+        })?;
+
+        parser(section).map_err(|error| error.context(context))
+    }
+
+    /// Parses the next `N` sections with `parser`.
+    pub fn array<T, const N: usize>(
+        &mut self,
+        what: &str,
+        mut parser: impl FnMut(&'a str) -> Result<T, ParseError>,
+    ) -> Result<[T; N], ParseError> {
+        let items = (0..N)
+            .map(|_| self.next(what, &mut parser))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match items.try_into() {
+            Ok(items) => Ok(items),
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Parses all remaining sections with `parser`.
+    pub fn repeat<T, B: FromIterator<T>>(
+        &mut self,
+        what: &str,
+        mut parser: impl FnMut(&'a str) -> Result<T, ParseError>,
+    ) -> Result<B, ParseError> {
+        std::iter::from_fn(|| (!self.rest.trim().is_empty()).then(|| self.next(what, &mut parser)))
+            .collect()
+    }
+
+    /// Checks that all sections have been parsed.
+    pub fn finish(mut self) -> Result<(), ParseError> {
+        match next_section(&mut self.rest) {
+            Some(section) => Err(ParseError::new(section, "unexpected section")),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Parses `separator`-separated `key{assign}value` pairs, such as `x=787,m=2655` with a
+/// separator of `","` and `"="` for `assign`.
+pub fn key_values<'a>(
+    text: &'a str,
+    separator: &str,
+    assign: &str,
+) -> Result<Vec<(&'a str, &'a str)>, ParseError> {
+    text.split(separator)
+        .map(|pair| split_once(pair, assign))
+        .collect()
+}
+
+enum Token<'a> {
+    Literal(&'a str),
+    Placeholder,
+}
+
+/// Splits `pattern` into literal text and `{...}` placeholders, where `{{` and `}}` are literal
+/// braces.
+fn tokenize(pattern: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = pattern;
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("{{") {
+            tokens.push(Token::Literal("{"));
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("}}") {
+            tokens.push(Token::Literal("}"));
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix('{') {
+            let end = after
+                .find('}')
+                .unwrap_or_else(|| panic!("unclosed placeholder in {pattern:?}"));
+            tokens.push(Token::Placeholder);
+            rest = &after[end + 1..];
+        } else {
+            let end = rest.find(['{', '}']).unwrap_or(rest.len());
+            assert!(end > 0, "unmatched '}}' in {pattern:?}");
+            tokens.push(Token::Literal(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+
+    tokens
+}
+
+/// Matches `text` against `pattern` and returns the text matched by each of its `N`
+/// placeholders. A placeholder is written as `{}` or `{name}`, where the name only serves as
+/// documentation, and `{{` and `}}` match literal braces. Each placeholder matches up to the
+/// first occurrence of the literal text after it, or up to the end of `text` if it is last.
+///
+/// For example, `pattern(line, "{name}{{{rules}}}")` splits `px{a<2006:qkq,rfg}` into `px` and
+/// `a<2006:qkq,rfg`.
+///
+/// # Panics
+///
+/// If `pattern` does not have exactly `N` placeholders, or has two placeholders without literal
+/// text in between.
+pub fn pattern<'a, const N: usize>(
+    text: &'a str,
+    pattern: &str,
+) -> Result<[&'a str; N], ParseError> {
+    let tokens = tokenize(pattern);
+    let num_placeholders = tokens
+        .iter()
+        .filter(|token| matches!(token, Token::Placeholder))
+        .count();
+    assert_eq!(
+        num_placeholders, N,
+        "wrong number of placeholders in {pattern:?}"
+    );
+
+    let mut captures = [""; N];
+    let mut num_captures = 0;
+    let mut rest = text;
+
+    for (i, token) in tokens.iter().enumerate() {
+        match *token {
+            Token::Literal(literal) => rest = strip_prefix(rest, literal)?,
+            Token::Placeholder => {
+                let end = match tokens.get(i + 1) {
+                    None => rest.len(),
+                    Some(Token::Literal(literal)) if i + 2 == tokens.len() => {
+                        if !rest.ends_with(literal) {
+                            return Err(ParseError::new(
+                                &rest[rest.len()..],
+                                format!("expected {literal:?}"),
+                            ));
+                        }
+                        rest.len() - literal.len()
+                    }
+                    Some(Token::Literal(literal)) => rest
+                        .find(literal)
+                        .ok_or_else(|| ParseError::new(rest, format!("expected {literal:?}")))?,
+                    Some(Token::Placeholder) => {
+                        panic!("adjacent placeholders in {pattern:?}")
+                    }
+                };
+
+                captures[num_captures] = &rest[..end];
+                num_captures += 1;
+                rest = &rest[end..];
+            }
+        }
+    }
+
+    if rest.is_empty() {
+        Ok(captures)
+    } else {
+        Err(ParseError::new(rest, "unexpected text"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate() {
+        let input = "1 2\n3 x\n";
+        let error = number::<u32>(&input[6..7]).unwrap_err().locate(input);
+
+        assert_eq!(error.line(), Some(2));
+        assert_eq!(error.column(), Some(3));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected a number (found \"x\")"
+        );
+    }
+
+    #[test]
+    fn locate_foreign_text() {
+        let error = number::<u32>("x").unwrap_err().locate("1 2\n3 4");
+
+        assert_eq!(error.line(), None);
+        assert_eq!(error.to_string(), "expected a number (found \"x\")");
+    }
+
+    #[test]
+    fn invalid_char() {
+        #[derive(Debug, PartialEq)]
+        struct Digit(u32);
+
+        impl TryFrom<char> for Digit {
+            type Error = ();
+
+            fn try_from(c: char) -> Result<Self, Self::Error> {
+                c.to_digit(10).map(Digit).ok_or(())
+            }
+        }
+
+        let input = "12\n3é4";
+        let line = input.lines().nth(1).unwrap();
+
+        assert_eq!(chars::<Digit, Vec<_>>("12"), Ok(vec![Digit(1), Digit(2)]));
+
+        let error = chars::<Digit, Vec<_>>(line).unwrap_err().locate(input);
+        assert_eq!(error.text(), "é");
+        assert_eq!((error.line(), error.column()), (Some(2), Some(2)));
+    }
+
+    #[test]
+    fn numbers() {
+        let input = " 41 48\t-3  ";
+
+        assert_eq!(super::numbers::<i32>(input), Ok(vec![41, 48, -3]));
+        assert_eq!(super::numbers::<i32>(""), Ok(vec![]));
+
+        let input = "4 4x8";
+        let error = super::numbers::<u32>(input).unwrap_err().locate(input);
+        assert_eq!(error.text(), "4x8");
+        assert_eq!(error.column(), Some(3));
+    }
+
+    #[test]
+    fn integers() {
+        let input = "x=-3, y=12..-1 - 4";
+
+        assert_eq!(super::integers::<i32>(input), Ok(vec![-3, 12, -1, 4]));
+        assert_eq!(super::integers::<i32>("none"), Ok(vec![]));
+
+        let error = super::integers::<u8>(input).unwrap_err().locate(input);
+        assert_eq!(error.text(), "-3");
+        assert_eq!(error.column(), Some(3));
+    }
+
+    #[test]
+    fn sections() {
+        let input = "\na\nb\n\n\nc\r\n \r\nd\n";
+
+        assert_eq!(
+            super::sections(input).collect::<Vec<_>>(),
+            ["a\nb", "c", "d"]
+        );
+        assert_eq!(super::sections("").count(), 0);
+    }
+
+    #[test]
+    fn key_values() {
+        assert_eq!(
+            super::key_values("x=787,m=2655", ",", "="),
+            Ok(vec![("x", "787"), ("m", "2655")])
+        );
+        assert_eq!(
+            super::key_values("x=787,m", ",", "=").unwrap_err().text(),
+            "m"
+        );
+    }
+
+    #[test]
+    fn pattern() {
+        assert_eq!(
+            super::pattern("px{a<2006:qkq,rfg}", "{name}{{{rules}}}"),
+            Ok(["px", "a<2006:qkq,rfg"])
+        );
+        assert_eq!(
+            super::pattern("AAA = (BBB, CCC)", "{} = ({}, {})"),
+            Ok(["AAA", "BBB", "CCC"])
+        );
+
+        let input = "AAA = (BBB; CCC)";
+        let error = super::pattern::<3>(input, "{} = ({}, {})")
+            .unwrap_err()
+            .locate(input);
+        assert_eq!(error.reason(), "expected \", \"");
+        assert_eq!(error.column(), Some(8));
+
+        let error = super::pattern::<2>("px{a", "{name}{{{rules}}}").unwrap_err();
+        assert_eq!(error.reason(), "expected \"}\"");
+    }
+
+    #[test]
+    fn sections_parser() {
+        let input = "seeds: 1 2\r\n\r\n1\r\n2\r\n\r\n3\r\n\r\n4\r\n5\r\n";
+        let numbers = |section: &str| section.lines().map(number).collect::<Result<Vec<u32>, _>>();
+
+        let mut sections = Sections::new(input);
+        assert_eq!(
+            sections.next("seeds", super::integers::<u32>),
+            Ok(vec![1, 2])
+        );
+        assert_eq!(
+            sections.array("numbers", numbers),
+            Ok([vec![1, 2], vec![3]])
+        );
+        assert_eq!(sections.repeat("numbers", numbers), Ok(vec![vec![4, 5]]));
+        assert_eq!(sections.finish(), Ok(()));
+
+        let input = "1\n\n2\nx\n";
+        let error = Sections::new(input)
+            .repeat::<_, Vec<_>>("numbers", numbers)
+            .unwrap_err()
+            .locate(input);
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 1: section 2 (numbers): expected a number (found \"x\")"
+        );
+
+        let error = Sections::new("1")
+            .array::<_, 2>("numbers", numbers)
+            .unwrap_err();
+        assert_eq!(error.reason(), "section 2 (numbers): expected numbers");
+
+        let mut sections = Sections::new("1\n\n2");
+        sections.next("numbers", numbers).unwrap();
+        assert_eq!(sections.finish().unwrap_err().text(), "2");
+    }
+}
@@ -0,0 +1,354 @@
+//! Shortest path searches, both over the cells of a [`Grid`] and over arbitrary states.
+//!
+//! The generic searches take the states to start from and a `successors` closure, so the state
+//! can carry more than a position. For example, a state of position, direction and number of
+//! straight steps turns movement constraints into an ordinary Dijkstra search.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::grid::{Grid, Pos};
+
+/// The distances from a start position to every reachable cell of a grid, as found by
+/// [`distance_map`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DistanceMap {
+    distances: Grid<Option<usize>>,
+    predecessors: Grid<Option<Pos>>,
+}
+
+impl DistanceMap {
+    /// The number of steps from the start to `pos`, or `None` if it cannot be reached.
+    pub fn distance(&self, pos: Pos) -> Option<usize> {
+        *self.distances.get(pos.x, pos.y)
+    }
+
+    /// The distance of every cell, or `None` for cells that cannot be reached.
+    pub fn distances(&self) -> &Grid<Option<usize>> {
+        &self.distances
+    }
+
+    /// The previous cell on a shortest path to `pos`, or `None` for the start and unreachable
+    /// cells.
+    pub fn predecessor(&self, pos: Pos) -> Option<Pos> {
+        *self.predecessors.get(pos.x, pos.y)
+    }
+
+    /// A shortest path from the start to `pos`, including both.
+    pub fn path_to(&self, pos: Pos) -> Option<Vec<Pos>> {
+        self.distance(pos)?;
+
+        let mut path = vec![pos];
+        while let Some(predecessor) = self.predecessor(*path.last().unwrap()) {
+            path.push(predecessor);
+        }
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+/// Finds the distance from `start` to every cell of `grid` with a breadth-first search, moving
+/// orthogonally. `passable(from, to)` decides whether a step from one cell to a neighbor is
+/// allowed.
+pub fn distance_map<T>(
+    grid: &Grid<T>,
+    start: Pos,
+    mut passable: impl FnMut(Pos, Pos) -> bool,
+) -> DistanceMap {
+    let mut distances = Grid::new(grid.width(), grid.height(), None);
+    let mut predecessors = Grid::new(grid.width(), grid.height(), None);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    *distances.get_mut(start.x, start.y) = Some(0);
+
+    while let Some((pos, distance)) = queue.pop_front() {
+        for (_, next) in grid.neighbors(pos) {
+            if distances.get(next.x, next.y).is_none() && passable(pos, next) {
+                *distances.get_mut(next.x, next.y) = Some(distance + 1);
+                *predecessors.get_mut(next.x, next.y) = Some(pos);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    DistanceMap {
+        distances,
+        predecessors,
+    }
+}
+
+struct Node<S> {
+    state: S,
+    cost: u64,
+    predecessor: Option<usize>,
+}
+
+/// The states that a search reached, with their lowest cost and the state they were reached
+/// from.
+pub struct Search<S> {
+    nodes: Vec<Node<S>>,
+    indices: HashMap<S, usize>,
+    goal: Option<usize>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    fn new() -> Self {
+        Self {
+            nodes: vec![],
+            indices: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// Records that `state` can be reached for `cost`, unless it could already be reached for
+    /// at most that cost. Returns the index of the state if it was updated.
+    fn relax(&mut self, state: S, cost: u64, predecessor: Option<usize>) -> Option<usize> {
+        match self.indices.get(&state) {
+            Some(&index) if self.nodes[index].cost <= cost => None,
+            Some(&index) => {
+                self.nodes[index].cost = cost;
+                self.nodes[index].predecessor = predecessor;
+                Some(index)
+            }
+            None => {
+                let index = self.nodes.len();
+                self.indices.insert(state.clone(), index);
+                self.nodes.push(Node {
+                    state,
+                    cost,
+                    predecessor,
+                });
+                Some(index)
+            }
+        }
+    }
+
+    /// The lowest cost to reach `state`, if it was reached.
+    pub fn cost(&self, state: &S) -> Option<u64> {
+        self.indices.get(state).map(|&index| self.nodes[index].cost)
+    }
+
+    /// All reached states with their lowest cost.
+    pub fn costs(&self) -> impl Iterator<Item = (&S, u64)> {
+        self.nodes.iter().map(|node| (&node.state, node.cost))
+    }
+
+    /// The goal state that ended the search and its cost, if one was reached.
+    pub fn goal(&self) -> Option<(&S, u64)> {
+        self.goal
+            .map(|index| (&self.nodes[index].state, self.nodes[index].cost))
+    }
+
+    /// A cheapest path from a start state to `state`, including both.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.indices.get(state).map(|&index| self.path(index))
+    }
+
+    /// A cheapest path from a start state to the goal state, including both.
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.goal.map(|index| self.path(index))
+    }
+
+    fn path(&self, mut index: usize) -> Vec<S> {
+        let mut path = vec![self.nodes[index].state.clone()];
+
+        while let Some(predecessor) = self.nodes[index].predecessor {
+            path.push(self.nodes[predecessor].state.clone());
+            index = predecessor;
+        }
+        path.reverse();
+
+        path
+    }
+}
+
+/// Searches breadth-first from `starts`, where every step costs 1, until a state matching
+/// `is_goal` is reached or all reachable states have been visited.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue: VecDeque<_> = starts
+        .into_iter()
+        .filter_map(|start| search.relax(start, 0, None))
+        .collect();
+
+    while let Some(index) = queue.pop_front() {
+        let state = search.nodes[index].state.clone();
+
+        if is_goal(&state) {
+            search.goal = Some(index);
+            break;
+        }
+
+        let cost = search.nodes[index].cost + 1;
+        for next in successors(&state) {
+            if !search.indices.contains_key(&next) {
+                queue.extend(search.relax(next, cost, Some(index)));
+            }
+        }
+    }
+
+    search
+}
+
+/// Searches from `starts` in order of increasing cost, until a state matching `is_goal` is
+/// reached or all reachable states have been visited. `successors` returns the states reachable
+/// from a state, with the cost of getting there.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(starts, successors, |_| 0, is_goal)
+}
+
+/// Like [`dijkstra`], but guided towards the goal by `heuristic`, which estimates the remaining
+/// cost from a state. The result is only optimal if the estimate never exceeds the actual cost
+/// and does not decrease by more than the cost of a step.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut search = Search::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(index) = search.relax(start, 0, None) {
+            heap.push(Reverse((estimate, 0, index)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        // The state has been reached more cheaply since this entry was pushed.
+        if cost > search.nodes[index].cost {
+            continue;
+        }
+
+        let state = search.nodes[index].state.clone();
+
+        if is_goal(&state) {
+            search.goal = Some(index);
+            break;
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            let estimate = next_cost + heuristic(&next);
+
+            if let Some(next) = search.relax(next, next_cost, Some(index)) {
+                heap.push(Reverse((estimate, next_cost, next)));
+            }
+        }
+    }
+
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_maze() -> Grid<bool> {
+        Grid::try_parse_with(
+            "\
+..#.
+.##.
+....",
+            |c| match c {
+                '.' => Ok(true),
+                '#' => Ok(false),
+                _ => Err(()),
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn distance_map() {
+        let maze = get_maze();
+        let map = super::distance_map(&maze, Pos::new(0, 0), |_, to| *maze.get(to.x, to.y));
+
+        assert_eq!(map.distance(Pos::new(3, 0)), Some(7));
+        assert_eq!(map.distance(Pos::new(2, 0)), None);
+        assert_eq!(
+            map.path_to(Pos::new(3, 1)),
+            Some(vec![
+                Pos::new(0, 0),
+                Pos::new(0, 1),
+                Pos::new(0, 2),
+                Pos::new(1, 2),
+                Pos::new(2, 2),
+                Pos::new(3, 2),
+                Pos::new(3, 1),
+            ])
+        );
+    }
+
+    #[test]
+    fn bfs() {
+        let search = super::bfs([1], |&n| [n * 2, n + 1], |&n| n == 10);
+
+        assert_eq!(search.goal(), Some((&10, 4)));
+        assert_eq!(search.goal_path(), Some(vec![1, 2, 4, 5, 10]));
+    }
+
+    #[test]
+    fn dijkstra_with_state() {
+        // Walking the maze costs 1 per step, but turning costs 10 extra.
+        let maze = get_maze();
+        let search = dijkstra(
+            [(Pos::new(0, 0), crate::Dir::S)],
+            |&(pos, dir)| {
+                maze.neighbors(pos)
+                    .filter(|&(_, next)| *maze.get(next.x, next.y))
+                    .map(move |(next_dir, next)| {
+                        ((next, next_dir), if next_dir == dir { 1 } else { 11 })
+                    })
+                    .collect::<Vec<_>>()
+            },
+            |&(pos, _)| pos == Pos::new(3, 0),
+        );
+
+        assert_eq!(search.goal().map(|(_, cost)| cost), Some(27));
+    }
+
+    #[test]
+    fn astar() {
+        let goal = Pos::new(3, 0);
+        let maze = get_maze();
+        let search = super::astar(
+            [Pos::new(0, 0)],
+            |&pos| {
+                maze.neighbors(pos)
+                    .filter(|&(_, next)| *maze.get(next.x, next.y))
+                    .map(|(_, next)| (next, 1))
+                    .collect::<Vec<_>>()
+            },
+            |pos| (pos.x.abs_diff(goal.x) + pos.y.abs_diff(goal.y)) as u64,
+            |&pos| pos == goal,
+        );
+
+        assert_eq!(search.goal(), Some((&goal, 7)));
+        assert_eq!(search.goal_path().map(|path| path.len()), Some(8));
+    }
+}
@@ -59,12 +59,6 @@ fn day_11() {
 }
 
 #[test]
-fn day_12_part_one() {
-    test_part_one(env!("CARGO_BIN_EXE_day_12"), 12);
-}
-
-#[test]
-#[ignore = "not yet implemented"]
 fn day_12() {
     test(env!("CARGO_BIN_EXE_day_12"), 12);
 }
@@ -90,12 +84,11 @@ fn day_16() {
 }
 
 #[test]
-fn day_18_part_one() {
-    test_part_one(env!("CARGO_BIN_EXE_day_18"), 18);
+fn day_17() {
+    test(env!("CARGO_BIN_EXE_day_17"), 17);
 }
 
 #[test]
-#[ignore = "not yet implemented"]
 fn day_18() {
     test(env!("CARGO_BIN_EXE_day_18"), 18);
 }
@@ -106,65 +99,45 @@ fn day_19() {
 }
 
 #[test]
-fn day_20_part_one() {
-    test_part_one(env!("CARGO_BIN_EXE_day_20"), 20);
-}
-
-#[test]
-#[ignore = "not yet implemented"]
 fn day_20() {
     test(env!("CARGO_BIN_EXE_day_20"), 20);
 }
 
 #[test]
-fn day_21_part_one() {
-    test_part_one(env!("CARGO_BIN_EXE_day_21"), 21);
-}
-
-#[test]
-#[ignore = "not yet implemented"]
 fn day_21() {
     test(env!("CARGO_BIN_EXE_day_21"), 21);
 }
 
 #[test]
 #[ignore = "too slow"]
-fn day_22_part_one() {
-    test_part_one(env!("CARGO_BIN_EXE_day_22"), 22);
-}
-
-#[test]
-#[ignore = "not yet implemented"]
 fn day_22() {
     test(env!("CARGO_BIN_EXE_day_22"), 22);
 }
 
 #[test]
-fn day_23_part_one() {
-    test_part_one(env!("CARGO_BIN_EXE_day_23"), 23);
-}
-
-#[test]
-#[ignore = "not yet implemented"]
 fn day_23() {
     test(env!("CARGO_BIN_EXE_day_23"), 23);
 }
 
 #[test]
-fn day_24_part_one() {
-    test_part_one(env!("CARGO_BIN_EXE_day_24"), 24);
+fn day_24() {
+    test(env!("CARGO_BIN_EXE_day_24"), 24);
 }
 
 #[test]
-#[ignore = "not yet implemented"]
-fn day_24() {
-    test(env!("CARGO_BIN_EXE_day_24"), 24);
+fn day_25() {
+    test(env!("CARGO_BIN_EXE_day_25"), 25);
 }
 
 fn parse_string(string: &str) -> (Option<&str>, Option<&str>) {
-    let mut splits = string.trim().split_terminator('\0');
+    // Parts that are not implemented yet, or that don't exist on the last day, have no expected
+    // answer to compare against.
+    let mut splits = string
+        .trim()
+        .split_terminator('\0')
+        .map(|split| (!matches!(split, "!not-implemented" | "!no-puzzle")).then_some(split));
 
-    (splits.next(), splits.next())
+    (splits.next().flatten(), splits.next().flatten())
 }
 
 fn test(exe: &str, day: u8) {
@@ -173,18 +146,6 @@ fn test(exe: &str, day: u8) {
     test_command(command.arg("--quiet").arg(format!("data/input/{day}")), day);
 }
 
-fn test_part_one(exe: &str, day: u8) {
-    let mut command = Command::new(exe);
-
-    test_command(
-        command
-            .arg("--quiet")
-            .arg("--one")
-            .arg(format!("data/input/{day}")),
-        day,
-    );
-}
-
 fn test_command(command: &mut Command, day: u8) {
     let output = command.output().unwrap();
     assert!(output.status.success());